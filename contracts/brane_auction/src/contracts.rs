@@ -1,15 +1,18 @@
 use core::panic;
 
 use cosmwasm_std::{
-    attr, entry_point, has_coins, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, QuerierWrapper, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery
+    attr, entry_point, from_json, to_json_binary, to_json_string, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Timestamp, Uint128, WasmMsg, WasmQuery, Event
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
 
 use cw_storage_plus::Bound;
 use url::Url;
 
 use crate::{error::ContractError, 
-    msgs::{CollectionParams, CreateMinterMsg, Sg2ExecuteMsg, CollectionInfo, RoyaltyInfoResponse, Sg721InstantiateMsg, TokensResponse, Cw721QueryMsg as Sg721QueryMsg, Config, ExecuteMsg, BaseMinterExecuteMsg, InstantiateMsg, PendingAuctionResponse, QueryMsg, SubmissionsResponse}, reply::{handle_collection_reply, handle_mint_reply}, state::{Auction, Bid, BidAssetAuction, SubmissionInfo, SubmissionItem, ASSET_AUCTION, CONFIG, NFT_AUCTION, OWNERSHIP_TRANSFER, PENDING_AUCTION, SUBMISSIONS, WINNING_BIDDER}};
+    events::{asset_auction_settled_event, auction_settled_event, auction_state_event, bid_event, config_updated_event, mint_reserve_low_event, no_bid_event, submission_event, vote_event},
+    msgs::{ArtistDiscount, ArtistProfileResponse, CuratorStatsResponse, DelegationResponse, VotingPowerResponse, FreeVoterResponse, FreeVotersResponse, ArtistProfilesResponse, BaseMinterConfigResponse, BaseMinterQueryMsg, MinterResponse, CollectionParams, CreateMinterMsg, Sg2ExecuteMsg, CollectionInfo, RoyaltyInfoResponse, Sg721InstantiateMsg, TokensResponse, NumTokensResponse, Cw721QueryMsg as Sg721QueryMsg, Config, ExecuteMsg, BaseMinterExecuteMsg, InstantiateMsg, MigrateMsg, PendingAuctionResponse, BidHistoryResponse, MintFeeSource, MintReserveResponse, QueryMsg, SubmissionsResponse, RoyaltyPolicy, Sg721ExecuteMsg, UpdateCollectionInfoMsg, Asset, AssetInfo, Cw20HookMsg, BurnStrategy, BurnedResponse, TreasuryResponse, BidAssetAuctionResponse, LiveNftAuctionResponse, AuctionState, BidGate, NoBidPolicy, PauseScope, PausedResponse, SubmissionStatus}, reply::{handle_collection_reply, handle_mint_reply}, state::{artist_profiles, legacy, ArtistProfile, CuratorRewards, CuratorStats, CURATOR_REWARDS, CURATOR_STATS, DELEGATIONS, DELEGATORS, FreeVoter, FREE_VOTERS, ALLOWLIST, AUCTION_STATE, Auction, Bid, BidAssetAuction, SubmissionInfo, SubmissionItem, Treasury, Donation, ASSET_AUCTION, BURNED, CONFIG, NFT_AUCTION, OWNERSHIP_TRANSFER, PAUSED, PENDING_AUCTION, PROXY_BID, ProxyBid, BIDS, MINT_RESERVE, NEXT_AUCTION_ID, ROYALTY_SHARES, SUBMISSION_FEES, SUBMISSION_OUTCOMES, SubmissionMetadata, submissions, TREASURY, WINNING_BIDDER}};
 
 
 // Contract name and version used for migration.
@@ -23,10 +26,24 @@ const MINT_REPLY_ID: u64 = 2u64;
 const SECONDS_PER_DAY: u64 = 86400u64;
 const DEFAULT_LIMIT: u32 = 32u32;
 const PENDING_AUCTION_LIMIT: u32 = 1024u32;
+const MAX_AUCTIONED_ASSETS: usize = 10usize;
+const TOKENS_PAGE_LIMIT: u32 = 100u32; //sg721 caps pages, owners are counted page by page
+const EMERGENCY_CANCEL_PERIOD: u64 = 7u64; //in days
+const NO_BID_EXTENSIONS: u64 = 3u64;
+const LOW_RESERVE_MINTS: u64 = 7u64;
+//Submission metadata limits
+const MAX_TITLE_LENGTH: usize = 100usize;
+const MAX_DESCRIPTION_LENGTH: usize = 1000usize;
+const MAX_METADATA_FIELD_LENGTH: usize = 64usize;
+const MAX_URL_LENGTH: usize = 512usize;
+const MAX_TRAITS: usize = 16usize;
+//Each delegator's NFTs are queried when its delegate votes
+const MAX_DELEGATORS: usize = 25usize;
 
 //INIT helpers
 const VOTE_PERIOD: u64 = 7u64;
 const AUCTION_PERIOD: u64 = 1u64;
+const SOFT_CLOSE_PERIOD: u64 = 600u64;
 const CURATION_THRESHOLD: Decimal = Decimal::percent(11);
 
 //Minter costs
@@ -43,10 +60,17 @@ pub fn instantiate(
     let mut submsgs: Vec<SubMsg> = vec![];
 
     
+    //Trading can't start in the past
+    if let Some(start_trading_time) = msg.start_trading_time {
+        if start_trading_time < env.block.time {
+            return Err(ContractError::CustomError { val: String::from("Start trading time can't be in the past") });
+        }
+    }
+    
     // Need to send 250_000_000ustars to initialize the collection
     if let Some(sg721_code_id) = msg.clone().sg721_code_id {
 
-        let collection_params = msg.clone().collection_params.unwrap_or_else(|| CollectionParams { 
+        let mut collection_params = msg.clone().collection_params.unwrap_or_else(|| CollectionParams { 
             code_id: sg721_code_id, 
             name: String::from("The International Brane Wave"), 
             symbol: String::from("BRANE"), 
@@ -63,6 +87,13 @@ pub fn instantiate(
                 }) 
             }
         });
+        //The contract must be the creator to keep the collection info & trading time mutable
+        if collection_params.info.creator != env.contract.address {
+            return Err(ContractError::CustomError { val: String::from("The contract must be the collection creator") });
+        }
+        if msg.start_trading_time.is_some() {
+            collection_params.info.start_trading_time = msg.start_trading_time;
+        }
         //instantiate the Collection
         let collection_msg = Sg2ExecuteMsg::CreateMinter (CreateMinterMsg::<Option<Sg721InstantiateMsg>> {
             init_msg: None,
@@ -84,18 +115,21 @@ pub fn instantiate(
         //add to msgs
         submsgs.push(submsg);
     } else {
-        //Verify the minter address
-        if let Some(minter_addr) = msg.minter_addr.clone() {
-            deps.api.addr_validate(&minter_addr)?;
+        //Verify the existing collection before it's needed at the first conclusion
+        match (msg.minter_addr.clone(), msg.sg721_addr.clone()) {
+            (Some(minter_addr), Some(sg721_addr)) => verify_collection(deps.as_ref(), &env, minter_addr, sg721_addr)?,
+            _ => return Err(ContractError::CustomError { val: String::from("Pass a sg721_code_id or both the minter_addr & sg721_addr") }),
         }
     }
 
     let config = Config {
         owner: info.sender.clone(),
-        free_vote_addr: deps.api.addr_validate(&msg.clone().free_vote_addr)?,
-        bid_denom: msg.clone().bid_denom,
+        bid_denom: validate_asset_info(deps.api, msg.clone().bid_denom)?,
         minimum_outbid: Decimal::percent(1),
-        incentive_denom: msg.clone().incentive_denom,
+        incentive_denom: match msg.clone().incentive_denom {
+            Some(incentive_denom) => Some(validate_asset_info(deps.api, incentive_denom)?),
+            None => None,
+        },
         // incentive_distribution_amount: 0u128, //set to 100_000_000u128 to pass integration_test
         incentive_bid_percent: Decimal::percent(10),
         current_submission_id: 0,
@@ -108,38 +142,71 @@ pub fn instantiate(
         submission_vote_period: VOTE_PERIOD,
         curation_threshold: CURATION_THRESHOLD,
         auction_period: AUCTION_PERIOD,
+        asset_auction_period: AUCTION_PERIOD,
+        soft_close_period: SOFT_CLOSE_PERIOD,
+        royalty_policy: RoyaltyPolicy::BidAssetAuction,
+        royalty_denom: String::from("ustars"),
+        burn_strategy: BurnStrategy::Burn,
+        guardian: None,
+        no_bid_policy: NoBidPolicy::Rotate { max_extensions: NO_BID_EXTENSIONS },
+        reserve_price: 0u128,
+        bid_gate: None,
+        mint_fee_source: MintFeeSource::Reserve,
+        low_reserve_mints: LOW_RESERVE_MINTS,
+        artist_discount: None,
+        curator_reward_percent: Decimal::zero(),
+        curator_hit_price: 0u128,
     };
 
     CONFIG.save(deps.storage, &config)?;
+    //The initial free voter gets 1 vote that doesn't expire
+    FREE_VOTERS.save(deps.storage, deps.api.addr_validate(&msg.free_vote_addr)?, &FreeVoter { weight: 1, expiration: None })?;
     PENDING_AUCTION.save(deps.storage, &vec![])?;
+    PAUSED.save(deps.storage, &vec![])?;
+    AUCTION_STATE.save(deps.storage, &AuctionState::Live)?;
+    TREASURY.save(deps.storage, &Treasury::default())?;
+    //ustars sent beyond the collection's creation fee fund mints
+    let mut mint_reserve = get_native_payment(&info, &AssetInfo::NativeToken { denom: String::from("ustars") });
+    if msg.sg721_code_id.is_some() {
+        mint_reserve = mint_reserve.saturating_sub(Uint128::new(MINTER_COST));
+    }
+    MINT_RESERVE.save(deps.storage, &mint_reserve)?;
 
     //verify the proceed recipient
     deps.api.addr_validate(&msg.first_submission.proceed_recipient.to_string())?;
     // Token URI must be a valid URL (ipfs, https, etc.)
     Url::parse(&msg.first_submission.token_uri).map_err(|_| ContractError::InvalidTokenURI { uri: msg.first_submission.token_uri.clone() })?;
     //Start first Auction
+    let auction_id = get_next_auction_id(deps.storage)?;
     NFT_AUCTION.save(deps.storage, &Auction {
+        submission_id: None,
         submission_info: SubmissionItem {
             submission: SubmissionInfo {
                 submitter: info.sender.clone(),
                 ..msg.first_submission
             },
+            metadata: None,
             curators: vec![],
+            delegated_voters: vec![],
             votes: 0u64,
             submission_end_time: env.block.time.seconds() + (VOTE_PERIOD * SECONDS_PER_DAY),
         },
-        bids: vec![],
+        auction_id,
+        bid_count: 0,
         auction_end_time: env.block.time.seconds() + (SECONDS_PER_DAY * config.auction_period),
         highest_bid: Bid {
             bidder: Addr::unchecked(""),
             amount: 0u128,
         },
+        reserve_price: config.reserve_price,
+        extensions: 0,
+        bid_gate: None,
     })?;
 
     Ok(Response::new()
         .add_submessages(submsgs)
+        .add_event(config_updated_event(&config)?)
         .add_attribute("method", "instantiate")
-        .add_attribute("config", format!("{:?}", config))
         .add_attribute("contract_address", env.contract.address)
     )
 }
@@ -151,16 +218,65 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    //Check the entry point's pause scope
+    if let Some(scope) = execute_scope(&msg) {
+        assert_not_paused(deps.storage, scope)?;
+    }
+
     match msg {
-        ExecuteMsg::SubmitNft { proceed_recipient, token_uri } => submit_nft(deps, env, info, proceed_recipient, token_uri),
+        ExecuteMsg::SubmitNft { proceed_recipient, token_uri, metadata } => {
+            let config = CONFIG.load(deps.storage)?;
+            let payment = get_native_payment(&info, &config.bid_denom);
+            submit_nft(deps, env, info.sender, payment, proceed_recipient, token_uri, metadata)
+        },
         ExecuteMsg::VoteToCurate { submission_ids } => curate_nft(deps, env, info, submission_ids),
-        ExecuteMsg::BidForNft {  } => bid_on_live_auction(deps, env, info),
-        ExecuteMsg::BidForAssets {  } => bid_for_bid_assets(deps, info),
+        ExecuteMsg::BidForNft { max_bid } => {
+            let config = CONFIG.load(deps.storage)?;
+            let current_bid = assert_bid_asset(&info, config.bid_denom)?;
+            bid_on_live_auction(deps, env, current_bid, max_bid)
+        },
+        ExecuteMsg::BidForAssets {  } => {
+            let config = CONFIG.load(deps.storage)?;
+            let incentive_denom = match config.incentive_denom {
+                Some(incentive_denom) => incentive_denom,
+                None => return Err(ContractError::CustomError { val: String::from("No live bid asset auction") }),
+            };
+            let current_bid = assert_bid_asset(&info, incentive_denom)?;
+            bid_for_bid_assets(deps, env, current_bid)
+        },
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
         ExecuteMsg::ConcludeAuction {  } => conclude_auction(deps, env),
+        ExecuteMsg::ConcludeBidAssetAuction {  } => settle_bid_asset_auction(deps, env),
+        ExecuteMsg::Donate {  } => {
+            let assets = info.funds.iter().map(|coin| Asset {
+                info: AssetInfo::NativeToken { denom: coin.denom.clone() },
+                amount: coin.amount,
+            }).collect();
+            donate(deps, info.sender, assets)
+        },
         // ExecuteMsg::MigrateMinter { new_code_id } => todo!(),
         // ExecuteMsg::MigrateContract { new_code_id } => migrate_contract(deps, env, info, new_code_id),
-        ExecuteMsg::UpdateConfig { owner, bid_denom, minimum_outbid, incentive_denom, curation_threshold, incentive_bid_percent, mint_cost, auction_period, submission_cost, submission_limit, submission_vote_period, free_vote_addr } => 
-        update_config(deps, info, owner, free_vote_addr, bid_denom, minimum_outbid, incentive_denom, incentive_bid_percent, mint_cost, submission_cost, submission_limit, submission_vote_period, curation_threshold, auction_period),
+        ExecuteMsg::UpdateConfig { owner, bid_denom, minimum_outbid, incentive_denom, curation_threshold, incentive_bid_percent, mint_cost, auction_period, asset_auction_period, soft_close_period, submission_cost, submission_limit, submission_vote_period, royalty_policy, royalty_denom, burn_strategy, guardian, no_bid_policy, reserve_price, mint_fee_source, low_reserve_mints, curator_reward_percent, curator_hit_price } => 
+        update_config(deps, info, owner, bid_denom, minimum_outbid, incentive_denom, incentive_bid_percent, mint_cost, submission_cost, submission_limit, submission_vote_period, curation_threshold, auction_period, asset_auction_period, soft_close_period, royalty_policy, royalty_denom, burn_strategy, guardian, no_bid_policy, reserve_price, mint_fee_source, low_reserve_mints, curator_reward_percent, curator_hit_price),
+        ExecuteMsg::Pause { scopes } => pause(deps, info, scopes),
+        ExecuteMsg::Unpause { scopes } => unpause(deps, info, scopes),
+        ExecuteMsg::Crank {  } => crank(deps, env),
+        ExecuteMsg::EmergencyCancelAuction { requeue, reason } => emergency_cancel_auction(deps, env, info, requeue, reason),
+        ExecuteMsg::UpdateRoyaltyInfo { royalty_info } => update_royalty_info(deps, info, royalty_info),
+        ExecuteMsg::UpdateStartTradingTime { start_trading_time } => update_start_trading_time(deps, env, info, start_trading_time),
+        ExecuteMsg::UpdateCollectionInfo { description, image, external_link, explicit_content } => update_collection_info(deps, info, description, image, external_link, explicit_content),
+        ExecuteMsg::FreezeCollectionInfo {  } => freeze_collection_info(deps, info),
+        ExecuteMsg::DistributeRoyalties {  } => distribute_royalties(deps, env),
+        ExecuteMsg::SetBidGate { gate, live_auction_only } => set_bid_gate(deps, info, gate, live_auction_only),
+        ExecuteMsg::UpdateArtistProfile { display_name, payout_address } => update_artist_profile(deps, info, display_name, payout_address),
+        ExecuteMsg::SetArtistDiscount { discount } => set_artist_discount(deps, info, discount),
+        ExecuteMsg::ClaimCuratorRewards {  } => claim_curator_rewards(deps, info),
+        ExecuteMsg::DelegateVotes { to } => delegate_votes(deps, info, to),
+        ExecuteMsg::Undelegate {  } => undelegate(deps, info),
+        ExecuteMsg::UpdateAllowlist { add, remove } => update_allowlist(deps, info, add, remove),
+        ExecuteMsg::FundMintReserve {  } => fund_mint_reserve(deps, info),
+        ExecuteMsg::AddFreeVoter { addr, weight, expiration } => add_free_voter(deps, env, info, addr, weight, expiration),
+        ExecuteMsg::RemoveFreeVoter { addr } => remove_free_voter(deps, info, addr),
         }
 }
 
@@ -168,10 +284,9 @@ fn update_config(
     deps: DepsMut,
     info: MessageInfo,
     owner: Option<String>,
-    free_vote_addr: Option<String>,
-    bid_denom: Option<String>,
+    bid_denom: Option<AssetInfo>,
     minimum_outbid: Option<Decimal>,
-    incentive_denom: Option<String>,
+    incentive_denom: Option<AssetInfo>,
     // incentive_distribution_amount: Option<u128>,
     incentive_bid_percent: Option<Decimal>,
     mint_cost: Option<u128>,
@@ -180,6 +295,18 @@ fn update_config(
     submission_vote_period: Option<u64>,
     curation_threshold: Option<Decimal>,
     auction_period: Option<u64>,
+    asset_auction_period: Option<u64>,
+    soft_close_period: Option<u64>,
+    royalty_policy: Option<RoyaltyPolicy>,
+    royalty_denom: Option<String>,
+    burn_strategy: Option<BurnStrategy>,
+    guardian: Option<String>,
+    no_bid_policy: Option<NoBidPolicy>,
+    reserve_price: Option<u128>,
+    mint_fee_source: Option<MintFeeSource>,
+    low_reserve_mints: Option<u64>,
+    curator_reward_percent: Option<Decimal>,
+    curator_hit_price: Option<u128>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
     let mut attrs = vec![];
@@ -205,17 +332,14 @@ fn update_config(
         OWNERSHIP_TRANSFER.save(deps.storage, &valid_addr)?; 
         attrs.push(attr("owner_transfer", valid_addr));
     }
-    if let Some(free_vote_addr) = free_vote_addr {
-        config.free_vote_addr = deps.api.addr_validate(&free_vote_addr)?;
-    }
     if let Some(bid_denom) = bid_denom {
-        config.bid_denom = bid_denom;
+        config.bid_denom = validate_asset_info(deps.api, bid_denom)?;
     }
     if let Some(minimum_outbid) = minimum_outbid {
         config.minimum_outbid = minimum_outbid;
     }
     if let Some(incentive_denom) = incentive_denom {
-        config.incentive_denom = Some(incentive_denom);
+        config.incentive_denom = Some(validate_asset_info(deps.api, incentive_denom)?);
     }
     // if let Some(incentive_distribution_amount) = incentive_distribution_amount {
     //     config.incentive_distribution_amount = incentive_distribution_amount;
@@ -241,13 +365,526 @@ fn update_config(
     if let Some(auction_period) = auction_period {
         config.auction_period = auction_period;
     }
+    if let Some(asset_auction_period) = asset_auction_period {
+        config.asset_auction_period = asset_auction_period;
+    }
+    if let Some(soft_close_period) = soft_close_period {
+        config.soft_close_period = soft_close_period;
+    }
+    if let Some(royalty_policy) = royalty_policy {
+        //Validate the treasury address
+        config.royalty_policy = match royalty_policy {
+            RoyaltyPolicy::Treasury { addr } => RoyaltyPolicy::Treasury { addr: deps.api.addr_validate(&addr)?.to_string() },
+            royalty_policy => royalty_policy,
+        };
+    }
+    if let Some(royalty_denom) = royalty_denom {
+        config.royalty_denom = royalty_denom;
+    }
+    if let Some(burn_strategy) = burn_strategy {
+        //Validate the sink address
+        config.burn_strategy = match burn_strategy {
+            BurnStrategy::Sink { addr } => BurnStrategy::Sink { addr: deps.api.addr_validate(&addr)?.to_string() },
+            burn_strategy => burn_strategy,
+        };
+    }
+    if let Some(guardian) = guardian {
+        config.guardian = Some(deps.api.addr_validate(&guardian)?);
+    }
+    if let Some(no_bid_policy) = no_bid_policy {
+        //The reserve price can't go negative
+        if let NoBidPolicy::LowerReserve { decrease } = no_bid_policy {
+            if decrease > Decimal::one() {
+                return Err(ContractError::CustomError { val: String::from("Reserve decrease can't exceed 100%") });
+            }
+        }
+        config.no_bid_policy = no_bid_policy;
+    }
+    if let Some(reserve_price) = reserve_price {
+        config.reserve_price = reserve_price;
+    }
+    if let Some(mint_fee_source) = mint_fee_source {
+        config.mint_fee_source = mint_fee_source;
+    }
+    if let Some(low_reserve_mints) = low_reserve_mints {
+        config.low_reserve_mints = low_reserve_mints;
+    }
+    if let Some(curator_reward_percent) = curator_reward_percent {
+        if curator_reward_percent > Decimal::one() {
+            return Err(ContractError::CustomError { val: String::from("Curator reward percent can't exceed 100%") });
+        }
+        config.curator_reward_percent = curator_reward_percent;
+    }
+    if let Some(curator_hit_price) = curator_hit_price {
+        config.curator_hit_price = curator_hit_price;
+    }
     
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new()
     .add_attributes(attrs)
+        .add_event(config_updated_event(&config)?)
         .add_attribute("method", "update_config")
-        .add_attribute("config", format!("{:?}", config))
+    )
+}
+
+/// Pause scope of an entry point, None if it can't be paused
+fn execute_scope(
+    msg: &ExecuteMsg,
+) -> Option<PauseScope> {
+    match msg {
+        ExecuteMsg::SubmitNft { .. } => Some(PauseScope::Submissions),
+        ExecuteMsg::VoteToCurate { .. } => Some(PauseScope::Voting),
+        ExecuteMsg::BidForNft { .. } => Some(PauseScope::NftBids),
+        ExecuteMsg::BidForAssets { } | ExecuteMsg::Donate { } => Some(PauseScope::AssetBids),
+        ExecuteMsg::ConcludeAuction { } | ExecuteMsg::ConcludeBidAssetAuction { } => Some(PauseScope::Conclusion),
+        //Cw20 hooks are checked in receive_cw20
+        _ => None,
+    }
+}
+
+fn assert_not_paused(
+    storage: &dyn Storage,
+    scope: PauseScope,
+) -> Result<(), ContractError> {
+    if PAUSED.load(storage)?.contains(&scope) {
+        return Err(ContractError::Paused { scope: format!("{:?}", scope) });
+    }
+    Ok(())
+}
+
+fn assert_owner_or_guardian(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(storage)?;
+
+    if info.sender != config.owner && Some(info.sender.clone()) != config.guardian {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    scopes: Vec<PauseScope>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_guardian(deps.storage, &info)?;
+
+    let mut paused = PAUSED.load(deps.storage)?;
+    for scope in scopes {
+        if !paused.contains(&scope) {
+            paused.push(scope);
+        }
+    }
+    PAUSED.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "pause")
+        .add_attribute("paused", to_json_string(&paused)?)
+    )
+}
+
+fn unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    scopes: Option<Vec<PauseScope>>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_guardian(deps.storage, &info)?;
+
+    let mut paused = PAUSED.load(deps.storage)?;
+    match scopes {
+        Some(scopes) => paused.retain(|scope| !scopes.contains(scope)),
+        None => paused.clear(),
+    }
+    PAUSED.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "unpause")
+        .add_attribute("paused", to_json_string(&paused)?)
+    )
+}
+
+/// Cancel a live NFT auction that is stuck, i.e. the mint msg fails
+fn emergency_cancel_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    requeue: bool,
+    reason: String,
+) -> Result<Response, ContractError> {
+    assert_owner_or_guardian(deps.storage, &info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let live_auction = NFT_AUCTION.load(deps.storage)?;
+
+    //Conclusion gets a grace period before the auction can be cancelled
+    if env.block.time.seconds() < live_auction.auction_end_time + (SECONDS_PER_DAY * EMERGENCY_CANCEL_PERIOD) {
+        return Err(ContractError::CustomError { val: format!("Auctions can only be cancelled {} days after they end", EMERGENCY_CANCEL_PERIOD) });
+    }
+
+    //Refund the highest bidder from escrow
+    if live_auction.highest_bid.amount > 0 {
+        let escrow = get_highest_bid_escrow(deps.storage, &live_auction)?;
+        PROXY_BID.remove(deps.storage);
+        msgs.push(asset_transfer_msg(&config.bid_denom, live_auction.highest_bid.bidder.to_string(), escrow)?);
+        TREASURY.update(deps.storage, |mut treasury| -> StdResult<Treasury> {
+            treasury.escrowed_bids -= escrow;
+            Ok(treasury)
+        })?;
+    }
+
+    //Record dropped submissions
+    if let (false, Some(submission_id)) = (requeue, live_auction.submission_id) {
+        SUBMISSION_OUTCOMES.save(deps.storage, submission_id, &SubmissionStatus::Cancelled)?;
+        pool_submission_fee(deps.storage, submission_id)?;
+    }
+
+    //Add the submission to the end of the pending auctions
+    if requeue {
+        PENDING_AUCTION.update(deps.storage, |mut auctions| -> Result<_, ContractError> {
+            if auctions.len() >= PENDING_AUCTION_LIMIT as usize {
+                return Err(ContractError::CustomError { val: String::from("Pending auctions are full, cancel without requeueing") });
+            }
+            auctions.push(Auction {
+                submission_id: live_auction.submission_id,
+                submission_info: live_auction.submission_info.clone(),
+                auction_id: 0, //will set when active
+                bid_count: 0,
+                auction_end_time: 0, //will set when active
+                highest_bid: Bid {
+                    bidder: Addr::unchecked(""),
+                    amount: 0u128,
+                },
+                reserve_price: 0, //will set when active
+                extensions: 0,
+                bid_gate: None,
+            });
+            Ok(auctions)
+        })?;
+    }
+
+    //Start the next pending auction
+    let next_state = start_next_auction(deps.storage, &env, &config)?;
+    let state_events = set_auction_state(deps.storage, next_state)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_events(state_events)
+        .add_attribute("method", "emergency_cancel_auction")
+        .add_attribute("reason", reason)
+        .add_attribute("token_uri", live_auction.submission_info.submission.token_uri)
+        .add_attribute("refunded_bidder", live_auction.highest_bid.bidder)
+        .add_attribute("refunded_bid", live_auction.highest_bid.amount.to_string())
+        .add_attribute("requeued", requeue.to_string())
+    )
+}
+
+fn update_royalty_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    royalty_info: Option<RoyaltyInfoResponse>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(royalty_info) = royalty_info.clone() {
+        deps.api.addr_validate(&royalty_info.payment_address)?;
+        if royalty_info.share > Decimal::one() {
+            return Err(ContractError::CustomError { val: String::from("Royalty share can't be more than 100%") });
+        }
+    }
+
+    //The contract is the collection creator so it can update the collection info
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.sg721_addr,
+        msg: to_json_binary(&Sg721ExecuteMsg::UpdateCollectionInfo {
+            collection_info: UpdateCollectionInfoMsg {
+                description: None,
+                image: None,
+                external_link: None,
+                explicit_content: None,
+                royalty_info: Some(royalty_info.clone()),
+                creator: None,
+            },
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("method", "update_royalty_info")
+        .add_attribute("royalty_info", to_json_string(&royalty_info)?)
+    )
+}
+
+fn update_start_trading_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_trading_time: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(start_trading_time) = start_trading_time {
+        if start_trading_time < env.block.time {
+            return Err(ContractError::CustomError { val: String::from("Start trading time can't be in the past") });
+        }
+    }
+
+    //The minter forwards the update to the sg721, the contract is the minter's admin
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.minter_addr,
+        msg: to_json_binary(&BaseMinterExecuteMsg::UpdateStartTradingTime(start_trading_time))?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("method", "update_start_trading_time")
+        .add_attribute("start_trading_time", to_json_string(&start_trading_time)?)
+    )
+}
+
+fn update_collection_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    description: Option<String>,
+    image: Option<String>,
+    external_link: Option<Option<String>>,
+    explicit_content: Option<bool>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(image) = image.clone() {
+        Url::parse(&image).map_err(|_| ContractError::CustomError { val: String::from("Invalid image URL") })?;
+    }
+    if let Some(Some(external_link)) = external_link.clone() {
+        Url::parse(&external_link).map_err(|_| ContractError::CustomError { val: String::from("Invalid external link") })?;
+    }
+
+    let collection_info = UpdateCollectionInfoMsg {
+        description,
+        image,
+        external_link,
+        explicit_content,
+        royalty_info: None,
+        creator: None,
+    };
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.sg721_addr,
+        msg: to_json_binary(&Sg721ExecuteMsg::UpdateCollectionInfo {
+            collection_info: collection_info.clone(),
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("method", "update_collection_info")
+        .add_attribute("collection_info", to_json_string(&collection_info)?)
+    )
+}
+
+fn freeze_collection_info(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.sg721_addr,
+        msg: to_json_binary(&Sg721ExecuteMsg::FreezeCollectionInfo)?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("method", "freeze_collection_info")
+    )
+}
+
+/// Verify an existing collection's minter & sg721 are paired & the contract can mint
+fn verify_collection(
+    deps: Deps,
+    env: &Env,
+    minter_addr: String,
+    sg721_addr: String,
+) -> Result<(), ContractError> {
+    let minter_addr = deps.api.addr_validate(&minter_addr)?;
+    let sg721_addr = deps.api.addr_validate(&sg721_addr)?;
+
+    //The minter must mint to the sg721
+    let minter_config: BaseMinterConfigResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: minter_addr.to_string(),
+        msg: to_json_binary(&BaseMinterQueryMsg::Config {})?,
+    })).map_err(|_| ContractError::CustomError { val: String::from("Failed to query the minter's config") })?;
+    if sg721_addr != minter_config.collection_address {
+        return Err(ContractError::CustomError { val: String::from("The minter doesn't mint to the sg721") });
+    }
+
+    //The sg721 must only accept mints from the minter
+    let minter: MinterResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: sg721_addr.to_string(),
+        msg: to_json_binary(&Sg721QueryMsg::Minter {})?,
+    })).map_err(|_| ContractError::CustomError { val: String::from("Failed to query the sg721's minter") })?;
+    if minter.minter != Some(minter_addr.to_string()) {
+        return Err(ContractError::CustomError { val: String::from("The sg721's minter isn't the minter_addr") });
+    }
+
+    //The base minter only mints for the collection creator
+    let collection_info: CollectionInfo<RoyaltyInfoResponse> = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: sg721_addr.to_string(),
+        msg: to_json_binary(&Sg721QueryMsg::CollectionInfo {})?,
+    })).map_err(|_| ContractError::CustomError { val: String::from("Failed to query the sg721's collection info") })?;
+    if collection_info.creator != env.contract.address {
+        return Err(ContractError::CustomError { val: String::from("The contract must be the collection creator to mint") });
+    }
+
+    Ok(())
+}
+
+/// Contract balance of a denom that is owed to bidders or held for the next mint
+fn get_reserved_balance(
+    storage: &dyn Storage,
+    config: &Config,
+    asset_info: &AssetInfo,
+) -> StdResult<Uint128> {
+    let mut reserved = Uint128::zero();
+
+    //Escrowed NFT bids & the next bid asset auction's pool
+    if *asset_info == config.bid_denom {
+        let treasury = TREASURY.load(storage)?;
+        reserved += treasury.escrowed_bids + treasury.escrowed_submission_fees + treasury.pool();
+    }
+    //Pending donations
+    for donation in TREASURY.load(storage)?.donations {
+        if *asset_info == donation.asset.info {
+            reserved += donation.asset.amount;
+        }
+    }
+    //Bid asset auction assets & escrowed bid
+    if let Ok(auction) = ASSET_AUCTION.load(storage) {
+        for asset in auction.auctioned_asset {
+            if *asset_info == asset.info {
+                reserved += asset.amount;
+            }
+        }
+        if Some(asset_info.clone()) == config.incentive_denom {
+            reserved += Uint128::new(auction.highest_bid.amount);
+        }
+    }
+    //Curator rewards
+    if Some(asset_info.clone()) == config.incentive_denom {
+        reserved += CURATOR_REWARDS.may_load(storage)?.unwrap_or_default().unclaimed;
+    }
+    //Mint fees
+    if *asset_info == (AssetInfo::NativeToken { denom: String::from("ustars") }) {
+        reserved += MINT_RESERVE.may_load(storage)?.unwrap_or_default();
+    }
+
+    Ok(reserved)
+}
+
+/// Royalties are the royalty_denom balance that isn't reserved
+fn distribute_royalties(
+    deps: DepsMut,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    //Initialize msgs
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    //Get royalty amount
+    let royalty_asset = AssetInfo::NativeToken { denom: config.royalty_denom.clone() };
+    let balance = deps.querier.query_balance(env.contract.address.clone(), config.royalty_denom.clone())?;
+    let reserved = get_reserved_balance(deps.storage, &config, &royalty_asset)?;
+    let royalties = balance.amount.checked_sub(reserved).unwrap_or_else(|_| Uint128::zero());
+    if royalties.is_zero() {
+        return Err(ContractError::CustomError { val: String::from("No royalties to distribute") });
+    }
+
+    match config.royalty_policy.clone() {
+        RoyaltyPolicy::BidAssetAuction => {
+            //Add to the live auction or start a new one
+            match ASSET_AUCTION.load(deps.storage) {
+                Ok(mut auction) => {
+                    add_to_basket(&mut auction.auctioned_asset, Asset {
+                        info: royalty_asset,
+                        amount: royalties,
+                    });
+                    ASSET_AUCTION.save(deps.storage, &auction)?;
+                },
+                Err(_) => {
+                    ASSET_AUCTION.save(deps.storage, &BidAssetAuction {
+                        auctioned_asset: vec![Asset {
+                            info: royalty_asset,
+                            amount: royalties,
+                        }],
+                        highest_bid: Bid {
+                            bidder: env.contract.address.clone(),
+                            amount: 0u128,
+                        },
+                        auction_end_time: env.block.time.seconds() + (SECONDS_PER_DAY * config.asset_auction_period),
+                    })?;
+                },
+            };
+        },
+        RoyaltyPolicy::Artists => {
+            let artists: Vec<(Addr, u64)> = ROYALTY_SHARES
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<(Addr, u64)>>>()?;
+            let total_shares: u64 = artists.iter().map(|(_, shares)| shares).sum();
+            if total_shares == 0 {
+                return Err(ContractError::CustomError { val: String::from("No past artists to split royalties with") });
+            }
+
+            //Split pro-rata to sold pieces
+            for (artist, shares) in artists {
+                let amount = royalties * Decimal::from_ratio(shares, total_shares);
+                if !amount.is_zero() {
+                    msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: artist.to_string(),
+                        amount: vec![Coin {
+                            denom: config.royalty_denom.clone(),
+                            amount,
+                        }],
+                    }));
+                }
+            }
+        },
+        RoyaltyPolicy::Treasury { addr } => {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: addr,
+                amount: vec![Coin {
+                    denom: config.royalty_denom.clone(),
+                    amount: royalties,
+                }],
+            }));
+        },
+    };
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "distribute_royalties")
+        .add_attribute("royalty_policy", to_json_string(&config.royalty_policy)?)
+        .add_attribute("royalties", royalties.to_string())
     )
 }
 
@@ -290,27 +927,76 @@ fn get_next_submission_id(
     Ok(submission_id)
 }
 
+fn fund_mint_reserve(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.funds.is_empty() || info.funds.iter().any(|coin| coin.denom != "ustars") {
+        return Err(ContractError::InvalidAsset { asset: String::from("Only ustars fund the mint reserve") });
+    }
+    let amount = get_native_payment(&info, &AssetInfo::NativeToken { denom: String::from("ustars") });
+
+    let mint_reserve = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default() + amount;
+    MINT_RESERVE.save(deps.storage, &mint_reserve)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "fund_mint_reserve")
+        .add_attribute("funder", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("mint_reserve", mint_reserve)
+    )
+}
+
+fn get_mint_reserve(
+    deps: Deps,
+) -> StdResult<MintReserveResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let reserve = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(MintReserveResponse {
+        reserve,
+        mints_remaining: reserve.u128().checked_div(config.mint_cost).unwrap_or(0) as u64,
+    })
+}
+
+fn get_next_auction_id(
+    storage: &mut dyn Storage,
+) -> StdResult<u64> {
+    let auction_id = NEXT_AUCTION_ID.may_load(storage)?.unwrap_or(0);
+    NEXT_AUCTION_ID.save(storage, &(auction_id + 1))?;
+
+    Ok(auction_id)
+}
+
 fn submit_nft(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    proceed_recipient: String,
+    sender: Addr,
+    payment: Uint128,
+    proceed_recipient: Option<String>,
     token_uri: String,
+    metadata: Option<SubmissionMetadata>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
     
     // Token URI must be a valid URL (ipfs, https, etc.)
-    Url::parse(&token_uri).map_err(|_| ContractError::InvalidTokenURI { uri: token_uri.clone() })?;
+    let url = Url::parse(&token_uri).map_err(|_| ContractError::InvalidTokenURI { uri: token_uri.clone() })?;
+    if let Some(metadata) = metadata.clone() {
+        //The metadata JSON is built around the token_uri, so it must be the image
+        let is_metadata_json = url.path().to_lowercase().ends_with(".json")
+            || (url.scheme() == "data" && url.path().starts_with("application/json"));
+        if is_metadata_json {
+            return Err(ContractError::CustomError { val: String::from("Submissions with metadata use the image as the token_uri, not a metadata JSON") });
+        }
+        validate_metadata(&metadata)?;
+    }
 
     //If submission is from a non-holder, it costs Some(bid_asset)
-    match check_if_collection_holder(deps.as_ref(), config.clone().sg721_addr, info.clone().sender, config.clone().free_vote_addr){
+    match check_if_collection_holder(deps.as_ref(), &env, config.clone().sg721_addr, sender.clone()){
         Ok(votes) => {
             if votes == 0 {
                 //Check if the submission cost was sent                
-                if !has_coins(&info.funds, &Coin {
-                    denom: config.bid_denom.clone(),
-                    amount: Uint128::new(config.submission_cost),
-                }) {
+                if payment < get_submission_cost(deps.as_ref(), &config, &sender)? {
                     return Err(ContractError::CustomError { val: String::from("Submission cost not sent") });
                 }
                 //Submission cost is used in the bid asset auction
@@ -319,6 +1005,14 @@ fn submit_nft(
         Err(e) => return Err(e),
     };
 
+    //Default to the artist's payout address, then the submitter
+    let proceed_recipient = match proceed_recipient {
+        Some(proceed_recipient) => deps.api.addr_validate(&proceed_recipient)?,
+        None => artist_profiles().may_load(deps.storage, sender.clone())?
+            .and_then(|profile| profile.payout_address)
+            .unwrap_or_else(|| sender.clone()),
+    };
+
     //Create a new submission
     let submission_id = {
         let submission_id = config.current_submission_id;
@@ -333,47 +1027,240 @@ fn submit_nft(
 
     let submission_info = SubmissionItem {
         submission: SubmissionInfo {            
-            submitter: info.sender.clone(),
-            proceed_recipient: deps.api.addr_validate(&proceed_recipient)?,
+            submitter: sender.clone(),
+            proceed_recipient,
             token_uri,
         },
+        metadata,
         curators: vec![],
+        delegated_voters: vec![],
         votes: 0u64,
         submission_end_time: env.block.time.seconds() + (config.submission_vote_period * SECONDS_PER_DAY),
     };
 
-    SUBMISSIONS.save(deps.storage, submission_id, &submission_info)?;
+    submissions().save(deps.storage, submission_id, &submission_info)?;
+    update_artist_stats(deps.storage, &sender, |profile| profile.submitted += 1)?;
+
+    //Anything sent is escrowed until the submission is resolved, then goes to the next bid asset auction
+    if !payment.is_zero() {
+        SUBMISSION_FEES.save(deps.storage, submission_id, &payment)?;
+        TREASURY.update(deps.storage, |mut treasury| -> StdResult<Treasury> {
+            treasury.escrowed_submission_fees += payment;
+            Ok(treasury)
+        })?;
+    }
 
     Ok(Response::new()
+        .add_event(submission_event(submission_id, payment, &submission_info)?)
         .add_attribute("method", "submit_nft")
         .add_attribute("submission_id", submission_id.to_string())
-        .add_attribute("submitter", info.sender)
-        .add_attribute("submission_info", format!("{:?}", submission_info))
+        .add_attribute("submitter", sender)
     )
 }
 
+fn validate_metadata(
+    metadata: &SubmissionMetadata,
+) -> Result<(), ContractError> {
+    let too_long = |field: &str| ContractError::CustomError { val: format!("Metadata {} is too long", field) };
+
+    if metadata.title.as_ref().is_some_and(|title| title.len() > MAX_TITLE_LENGTH) {
+        return Err(too_long("title"));
+    }
+    if metadata.description.as_ref().is_some_and(|description| description.len() > MAX_DESCRIPTION_LENGTH) {
+        return Err(too_long("description"));
+    }
+    if metadata.artist_name.as_ref().is_some_and(|artist_name| artist_name.len() > MAX_METADATA_FIELD_LENGTH) {
+        return Err(too_long("artist_name"));
+    }
+    if let Some(content_type) = metadata.content_type.clone() {
+        if content_type.len() > MAX_METADATA_FIELD_LENGTH {
+            return Err(too_long("content_type"));
+        }
+        //MIME types are type/subtype
+        if content_type.split('/').filter(|part| !part.is_empty()).count() != 2 {
+            return Err(ContractError::CustomError { val: String::from("Metadata content_type must be a MIME type") });
+        }
+    }
+    if let Some(animation_url) = metadata.animation_url.clone() {
+        if animation_url.len() > MAX_URL_LENGTH {
+            return Err(too_long("animation_url"));
+        }
+        Url::parse(&animation_url).map_err(|_| ContractError::InvalidTokenURI { uri: animation_url.clone() })?;
+    }
+    if metadata.traits.len() > MAX_TRAITS {
+        return Err(ContractError::CustomError { val: format!("Metadata can't have more than {} traits", MAX_TRAITS) });
+    }
+    for trait_ in metadata.traits.iter() {
+        if trait_.trait_type.is_empty() || trait_.value.is_empty() {
+            return Err(ContractError::CustomError { val: String::from("Metadata traits can't be empty") });
+        }
+        if trait_.trait_type.len() > MAX_METADATA_FIELD_LENGTH || trait_.value.len() > MAX_METADATA_FIELD_LENGTH {
+            return Err(too_long("trait"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Votes of a free voter or collection holder
 fn check_if_collection_holder(
     deps: Deps,
+    env: &Env,
     sg721_addr: String,
     sender: Addr,
-    free_vote_addr: Addr
 ) -> Result<u64, ContractError> {  
-    //If sender is the founder, they are valid
-    if sender == free_vote_addr {
-        return Ok(1);
+    //Free voters vote with their weight until they expire
+    if let Some(free_voter) = FREE_VOTERS.may_load(deps.storage, sender.clone())? {
+        let expired = match free_voter.expiration {
+            Some(expiration) => env.block.time.seconds() >= expiration,
+            None => false,
+        };
+        if !expired {
+            return Ok(free_voter.weight);
+        }
+    }
+
+    get_collection_nfts(deps, sg721_addr, &sender)
+}
+
+/// Votes delegated to the delegate, per delegator with votes
+fn get_delegated_votes(
+    deps: Deps,
+    env: &Env,
+    sg721_addr: String,
+    delegate: &Addr,
+) -> Result<Vec<(Addr, u64)>, ContractError> {
+    let mut delegated_votes: Vec<(Addr, u64)> = vec![];
+    for delegator in DELEGATORS.may_load(deps.storage, delegate.clone())?.unwrap_or_default() {
+        let delegator_votes = check_if_collection_holder(deps, env, sg721_addr.clone(), delegator.clone())?;
+        if delegator_votes > 0 {
+            delegated_votes.push((delegator, delegator_votes));
+        }
+    }
+
+    Ok(delegated_votes)
+}
+
+/// Votes an address curates with, its own & delegated
+fn get_voting_power(
+    deps: Deps,
+    env: Env,
+    addr: String,
+) -> Result<VotingPowerResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let addr = deps.api.addr_validate(&addr)?;
+
+    let votes = check_if_collection_holder(deps, &env, config.clone().sg721_addr, addr.clone())?;
+    let delegated_votes: u64 = get_delegated_votes(deps, &env, config.sg721_addr, &addr)?
+        .iter()
+        .map(|(_, delegator_votes)| delegator_votes)
+        .sum();
+
+    Ok(VotingPowerResponse {
+        votes,
+        delegated_votes,
+        total: votes + delegated_votes,
+    })
+}
+
+/// NFTs the address holds in the collection
+fn get_collection_nfts(
+    deps: Deps,
+    sg721_addr: String,
+    addr: &Addr,
+) -> Result<u64, ContractError> {
+    let mut nfts = 0u64;
+    let mut start_after: Option<String> = None;
+    loop {
+        let token_info: TokensResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: sg721_addr.clone(),
+            msg: to_json_binary(&Sg721QueryMsg::Tokens { owner: addr.to_string(), start_after, limit: Some(TOKENS_PAGE_LIMIT) })?,
+        })).map_err(|_| ContractError::CustomError { val: String::from("Failed to query collection, sender may not hold an NFT") })?;
+
+        nfts += token_info.tokens.len() as u64;
+        //A partial page is the last one
+        if token_info.tokens.len() < TOKENS_PAGE_LIMIT as usize {
+            break;
+        }
+        start_after = token_info.tokens.last().cloned();
     }
 
-    //Check if the sender is a collection holder
-    let token_info: TokensResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: sg721_addr,
-        msg: to_json_binary(&Sg721QueryMsg::Tokens { owner: sender.to_string(), start_after: None, limit: None })?,
-    })).map_err(|_| ContractError::CustomError { val: String::from("Failed to query collection, sender may not hold an NFT") })?;
+    Ok(nfts)
+}
+
+/// Votes needed to pass curation, a % of the collection's supply
+fn get_passing_threshold(
+    deps: Deps,
+    config: &Config,
+) -> StdResult<u128> {
+    let mut passing_threshold = 1u128;
+    match deps.querier.query::<NumTokensResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: config.clone().sg721_addr,
+        msg: to_json_binary(&Sg721QueryMsg::NumTokens {})?,
+    })){
+        Ok(token_info) => {
+            let total_votes = token_info.count;
+            passing_threshold = (Uint128::new(total_votes as u128) * config.curation_threshold).u128();
+        },
+        Err(_) => { 
+        }
+    
+    };
+
+    Ok(passing_threshold)
+}
 
-    if token_info.tokens.is_empty() {
-        return Ok(0)
+/// Remove submissions past their vote period that didn't pass, returns the amount removed
+fn prune_expired_submissions(
+    storage: &mut dyn Storage,
+    env: &Env,
+    passing_threshold: u128,
+) -> StdResult<u64> {
+    //Submissions that ended before this block
+    let expired: Vec<(u64, SubmissionItem)> = submissions()
+        .idx.submission_end_time
+        .range(storage, None, Some(Bound::exclusive((env.block.time.seconds(), 0u64))), Order::Ascending)
+        .take(DEFAULT_LIMIT as usize)
+        .collect::<StdResult<Vec<(u64, SubmissionItem)>>>()?;
+
+    let mut pruned = 0u64;
+    for (submission_id, submission_info) in expired {
+        if submission_info.votes < passing_threshold as u64 {
+            expire_submission(storage, submission_id, &submission_info)?;
+            pruned += 1;
+        }
     }
 
-    Ok(token_info.tokens.len() as u64)
+    Ok(pruned)
+}
+
+/// Remove a submission that didn't pass its vote period, its fee goes to the next bid asset auction
+fn expire_submission(
+    storage: &mut dyn Storage,
+    submission_id: u64,
+    submission_info: &SubmissionItem,
+) -> StdResult<()> {
+    submissions().remove(storage, submission_id)?;
+    SUBMISSION_OUTCOMES.save(storage, submission_id, &SubmissionStatus::Expired)?;
+    pool_submission_fee(storage, submission_id)?;
+    score_curators(storage, &submission_info.curators, false)
+}
+
+/// Move a resolved submission's escrowed fee to the next bid asset auction
+fn pool_submission_fee(
+    storage: &mut dyn Storage,
+    submission_id: u64,
+) -> StdResult<()> {
+    if let Some(fee) = SUBMISSION_FEES.may_load(storage, submission_id)? {
+        SUBMISSION_FEES.remove(storage, submission_id);
+        TREASURY.update(storage, |mut treasury| -> StdResult<Treasury> {
+            treasury.escrowed_submission_fees -= fee;
+            treasury.submission_fees += fee;
+            Ok(treasury)
+        })?;
+    }
+
+    Ok(())
 }
 
 fn curate_nft(
@@ -390,46 +1277,43 @@ fn curate_nft(
     }
 
     //Make sure the sender is a collection holder
-    let votes = check_if_collection_holder(deps.as_ref(), config.clone().sg721_addr, info.clone().sender, config.clone().free_vote_addr)?;
+    let votes = check_if_collection_holder(deps.as_ref(), &env, config.clone().sg721_addr, info.clone().sender)?;
+
+    //Get the votes delegated to the sender
+    let delegated_votes = get_delegated_votes(deps.as_ref(), &env, config.clone().sg721_addr, &info.sender)?;
+    let total_votes = votes + delegated_votes.iter().map(|(_, delegator_votes)| delegator_votes).sum::<u64>();
 
     //Error if votes are 0
-    if votes == 0 {
+    if total_votes == 0 {
         return Err(ContractError::CustomError { val: String::from("Sender does not hold an NFT") });
     }
 
     //Get the Curation passing threshold
-    let mut passing_threshold = 1u128;
-    match deps.querier.query::<TokensResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: config.clone().sg721_addr,
-        msg: to_json_binary(&Sg721QueryMsg::AllTokens { start_after: None, limit: None })?,
-    })){
-        Ok(token_info) => {
-            let total_votes = token_info.tokens.len();
-            passing_threshold = (Uint128::new(total_votes as u128) * config.curation_threshold).u128();
-        },
-        Err(_) => { 
-        }
-    
-    };
+    let passing_threshold = get_passing_threshold(deps.as_ref(), &config)?;
+
+    //Submissions voted on & submissions that passed
+    let mut voted: Vec<u64> = vec![];
+    let mut passed: Vec<u64> = vec![];
+    let mut state_events: Vec<Event> = vec![];
 
     //Update the submission info
     for submission_id in submission_ids.clone() {
         //Load submission info
-        let mut submission_info = match SUBMISSIONS.load(deps.storage, submission_id){
+        let mut submission_info = match submissions().load(deps.storage, submission_id){
             Ok(submission) => submission,
             Err(_) => return Err(ContractError::CustomError { val: String::from("Submission not found, maybe its already a valid auction") }),
         
         };
     
-        // Assert they haven't voted yet
-        if submission_info.curators.contains(&info.clone().sender) {
+        // Assert they haven't voted yet, directly or through their delegate
+        if submission_info.curators.contains(&info.clone().sender) || submission_info.delegated_voters.contains(&info.sender) {
             continue;
         }
         /// Assert the submission is still in the voting period
         //If its past the submission period and the submission doesn't have enough votes, remove it
         if env.block.time.seconds() > submission_info.submission_end_time {
             if submission_info.votes < passing_threshold as u64 {
-                SUBMISSIONS.remove(deps.storage, submission_id);
+                expire_submission(deps.storage, submission_id, &submission_info)?;
                 //Subtract from the submission total
                 config.submission_total -= 1;
                 continue;
@@ -440,68 +1324,223 @@ fn curate_nft(
             //Tally the vote
             submission_info.curators.push(info.sender.clone());
             submission_info.votes += votes;
+            //Tally delegated votes, skipping delegators that already voted
+            for (delegator, delegator_votes) in delegated_votes.iter() {
+                if submission_info.curators.contains(delegator) || submission_info.delegated_voters.contains(delegator) {
+                    continue;
+                }
+                submission_info.delegated_voters.push(delegator.clone());
+                submission_info.votes += delegator_votes;
+            }
+            voted.push(submission_id);
 
             
             //If the submission has enough votes, add it to the list of auctionables
-            if submission_info.votes >= passing_threshold as u64 {
+            let artist_threshold = get_artist_passing_threshold(deps.as_ref(), &config, &submission_info.submission.submitter, passing_threshold)?;
+            if submission_info.votes >= artist_threshold as u64 {
+                update_artist_stats(deps.storage, &submission_info.submission.submitter, |profile| profile.curated += 1)?;
                 //Set as live auction if there is none, else add to pending auctions
                 if let Err(_) = NFT_AUCTION.load(deps.storage) {
+                    let auction_id = get_next_auction_id(deps.storage)?;
                     NFT_AUCTION.save(deps.storage, &Auction {
+                        submission_id: Some(submission_id),
                         submission_info: submission_info.clone(),
-                        bids: vec![],
+                        auction_id,
+                        bid_count: 0,
                         auction_end_time: env.block.time.seconds() + (SECONDS_PER_DAY * config.clone().auction_period),
                         highest_bid: Bid {
                             bidder: Addr::unchecked(""),
                             amount: 0u128,                            
                         },
+                        reserve_price: config.reserve_price,
+                        extensions: 0,
+                        bid_gate: None,
                     })?;
+                    state_events.extend(set_auction_state(deps.storage, AuctionState::Live)?);
                 } else {
                     PENDING_AUCTION.update(deps.storage, |mut auctions| -> Result<_, ContractError> {
                         if !(auctions.len() >= PENDING_AUCTION_LIMIT as usize) {
                             auctions.push(Auction {
+                                submission_id: Some(submission_id),
                                 submission_info: submission_info.clone(),
-                                bids: vec![],
+                                auction_id: 0, //will set when active
+                                bid_count: 0,
                                 auction_end_time: 0, //will set when active
                                 highest_bid: Bid {
                                     bidder: Addr::unchecked(""),
                                     amount: 0u128,                            
                                 },
+                                reserve_price: 0, //will set when active
+                                extensions: 0,
+                                bid_gate: None,
                             });                            
                         }
                         Ok(auctions)
                     })?;
                 }
-                SUBMISSIONS.remove(deps.storage, submission_id);
+                submissions().remove(deps.storage, submission_id)?;
                 //Subtract from the submission total
                 config.submission_total -= 1;
+                passed.push(submission_id);
             } else {
                 //If the submission doesn't have enough votes yet, save it
-                SUBMISSIONS.save(deps.storage, submission_id, &submission_info)?;                
+                submissions().save(deps.storage, submission_id, &submission_info)?;                
             }
             
         }
     }
 
+    //Remove expired submissions
+    config.submission_total -= prune_expired_submissions(deps.storage, &env, passing_threshold)?;
+
     //Save submission total
     CONFIG.save(deps.storage, &config)?;
 
 
     Ok(Response::new()
+        .add_event(vote_event(&info.sender, total_votes, &voted, &passed)?)
+        .add_events(state_events)
         .add_attribute("method", "curate_nft")
-        .add_attribute("submission_ids", format!("{:?}", submission_ids))
+        .add_attribute("submission_ids", to_json_string(&submission_ids)?)
         .add_attribute("curator", info.sender)
     )
 }
 
+fn validate_asset_info(
+    api: &dyn Api,
+    asset_info: AssetInfo,
+) -> StdResult<AssetInfo> {
+    match asset_info {
+        AssetInfo::NativeToken { denom } => Ok(AssetInfo::NativeToken { denom }),
+        AssetInfo::Token { address } => Ok(AssetInfo::Token { address: api.addr_validate(address.as_ref())? }),
+    }
+}
+
+/// Amount of a native asset sent with the msg, cw20s are sent through Receive
+fn get_native_payment(
+    info: &MessageInfo,
+    asset_info: &AssetInfo,
+) -> Uint128 {
+    match asset_info {
+        AssetInfo::NativeToken { denom } => info.funds
+            .iter()
+            .filter(|coin| coin.denom == *denom)
+            .map(|coin| coin.amount)
+            .sum(),
+        AssetInfo::Token { .. } => Uint128::zero(),
+    }
+}
+
+fn asset_transfer_msg(
+    asset_info: &AssetInfo,
+    recipient: String,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    match asset_info {
+        AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient,
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount,
+            }],
+        })),
+        AssetInfo::Token { address } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer { recipient, amount })?,
+            funds: vec![],
+        })),
+    }
+}
+
+/// Burn an asset held by the contract & add it to the burned total
+fn burn_asset(
+    storage: &mut dyn Storage,
+    env: &Env,
+    burn_strategy: &BurnStrategy,
+    asset: Asset,
+) -> StdResult<CosmosMsg> {
+    BURNED.update(storage, asset.info.to_string(), |burned| -> StdResult<Asset> {
+        let mut burned = burned.unwrap_or_else(|| Asset { info: asset.info.clone(), amount: Uint128::zero() });
+        burned.amount += asset.amount;
+        Ok(burned)
+    })?;
+
+    match (burn_strategy, &asset.info) {
+        (BurnStrategy::Sink { addr }, _) => asset_transfer_msg(&asset.info, addr.clone(), asset.amount),
+        (_, AssetInfo::Token { address }) => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount: asset.amount })?,
+            funds: vec![],
+        })),
+        //MsgBurn only works for denoms the contract is the admin of
+        (BurnStrategy::TokenFactory, AssetInfo::NativeToken { denom }) if denom.starts_with(&format!("factory/{}/", env.contract.address)) => Ok(MsgBurn {
+            sender: env.contract.address.to_string(),
+            amount: Some(osmosis_std::types::cosmos::base::v1beta1::Coin { denom: denom.clone(), amount: asset.amount.to_string() }),
+            burn_from_address: env.contract.address.to_string(),
+        }.into()),
+        (_, AssetInfo::NativeToken { denom }) => Ok(CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: asset.amount,
+            }],
+        })),
+    }
+}
+
+fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    //The sender is the cw20 contract
+    let sent_asset = AssetInfo::Token { address: info.sender.clone() };
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+
+    let hook_msg: Cw20HookMsg = from_json(&cw20_msg.msg)?;
+    assert_not_paused(deps.storage, match hook_msg {
+        Cw20HookMsg::SubmitNft { .. } => PauseScope::Submissions,
+        Cw20HookMsg::BidForNft { .. } => PauseScope::NftBids,
+        Cw20HookMsg::BidForAssets { } | Cw20HookMsg::Donate { } => PauseScope::AssetBids,
+    })?;
+
+    match hook_msg {
+        Cw20HookMsg::SubmitNft { proceed_recipient, token_uri, metadata } => {
+            if sent_asset != config.bid_denom {
+                return Err(ContractError::InvalidAsset { asset: sent_asset.to_string() });
+            }
+            submit_nft(deps, env, sender, cw20_msg.amount, proceed_recipient, token_uri, metadata)
+        },
+        Cw20HookMsg::BidForNft { max_bid } => {
+            if sent_asset != config.bid_denom {
+                return Err(ContractError::InvalidAsset { asset: sent_asset.to_string() });
+            }
+            bid_on_live_auction(deps, env, Bid { bidder: sender, amount: cw20_msg.amount.u128() }, max_bid)
+        },
+        Cw20HookMsg::Donate {  } => donate(deps, sender, vec![Asset { info: sent_asset, amount: cw20_msg.amount }]),
+        Cw20HookMsg::BidForAssets {  } => {
+            if Some(sent_asset.clone()) != config.incentive_denom {
+                return Err(ContractError::InvalidAsset { asset: sent_asset.to_string() });
+            }
+            bid_for_bid_assets(deps, env, Bid { bidder: sender, amount: cw20_msg.amount.u128() })
+        },
+    }
+}
+
 fn assert_bid_asset(
     info: &MessageInfo,
-    bid_denom: String,    
+    bid_denom: AssetInfo,    
 ) -> Result<Bid, ContractError> {
+    let denom = match bid_denom {
+        AssetInfo::NativeToken { denom } => denom,
+        AssetInfo::Token { address } => return Err(ContractError::InvalidAsset { asset: format!("{} bids are sent through the cw20 Receive hook", address) }),
+    };
     if info.funds.len() != 1 {
         return Err(ContractError::InvalidAsset { asset: String::from("None or more than 1 asset sent") });
     }
     //Check if the bid asset was sent
-    if info.funds[0].denom != bid_denom {
+    if info.funds[0].denom != denom {
         return Err(ContractError::InvalidAsset { asset: String::from("Bid asset not sent") });
     }
 
@@ -511,17 +1550,528 @@ fn assert_bid_asset(
     })
 }
 
-fn bid_on_live_auction(
+fn set_bid_gate(
+    deps: DepsMut,
+    info: MessageInfo,
+    gate: Option<BidGate>,
+    live_auction_only: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    //Incentive balances are checked in the incentive_denom
+    if let (Some(BidGate::IncentiveBalance { .. }), None) = (&gate, &config.incentive_denom) {
+        return Err(ContractError::CustomError { val: String::from("Incentive balance gate requires an incentive_denom") });
+    }
+
+    if live_auction_only {
+        let mut live_auction = NFT_AUCTION.load(deps.storage)
+            .map_err(|_| ContractError::CustomError { val: String::from("No live auction") })?;
+        live_auction.bid_gate = gate.clone();
+        NFT_AUCTION.save(deps.storage, &live_auction)?;
+    } else {
+        config.bid_gate = gate.clone();
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "set_bid_gate")
+        .add_attribute("gate", to_json_string(&gate)?)
+        .add_attribute("live_auction_only", live_auction_only.to_string())
+    )
+}
+
+fn update_artist_profile(
+    deps: DepsMut,
+    info: MessageInfo,
+    display_name: Option<String>,
+    payout_address: Option<String>,
+) -> Result<Response, ContractError> {
+    if display_name.as_ref().is_some_and(|display_name| display_name.len() > MAX_METADATA_FIELD_LENGTH) {
+        return Err(ContractError::CustomError { val: String::from("Display name is too long") });
+    }
+    let payout_address = match payout_address {
+        Some(payout_address) if payout_address.is_empty() => Some(None),
+        Some(payout_address) => Some(Some(deps.api.addr_validate(&payout_address)?)),
+        None => None,
+    };
+
+    let profile = update_artist_stats(deps.storage, &info.sender, |profile| {
+        if let Some(display_name) = display_name {
+            profile.display_name = if display_name.is_empty() { None } else { Some(display_name) };
+        }
+        if let Some(payout_address) = payout_address {
+            profile.payout_address = payout_address;
+        }
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_artist_profile")
+        .add_attribute("artist", info.sender)
+        .add_attribute("profile", to_json_string(&profile)?)
+    )
+}
+
+fn set_artist_discount(
+    deps: DepsMut,
+    info: MessageInfo,
+    discount: Option<ArtistDiscount>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if let Some(discount) = discount.clone() {
+        if discount.submission_cost_discount > Decimal::one() || discount.curation_threshold_discount > Decimal::one() {
+            return Err(ContractError::CustomError { val: String::from("Artist discounts can't be more than 100%") });
+        }
+    }
+
+    config.artist_discount = discount.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_artist_discount")
+        .add_attribute("discount", to_json_string(&discount)?)
+    )
+}
+
+/// Delegate the sender's curation votes
+fn delegate_votes(
+    deps: DepsMut,
+    info: MessageInfo,
+    to: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&to)?;
+    if delegate == info.sender {
+        return Err(ContractError::CustomError { val: String::from("Can't delegate votes to yourself") });
+    }
+
+    //Replace the current delegation
+    remove_delegation(deps.storage, &info.sender)?;
+    let mut delegators = DELEGATORS.may_load(deps.storage, delegate.clone())?.unwrap_or_default();
+    if delegators.len() >= MAX_DELEGATORS {
+        return Err(ContractError::CustomError { val: format!("Delegate already has the max of {} delegators", MAX_DELEGATORS) });
+    }
+    delegators.push(info.sender.clone());
+    DELEGATORS.save(deps.storage, delegate.clone(), &delegators)?;
+    DELEGATIONS.save(deps.storage, info.sender.clone(), &delegate)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "delegate_votes")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate)
+    )
+}
+
+fn undelegate(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegate = remove_delegation(deps.storage, &info.sender)?
+        .ok_or_else(|| ContractError::CustomError { val: String::from("Sender hasn't delegated their votes") })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate)
+    )
+}
+
+/// Remove a delegator's delegation, returns the old delegate
+fn remove_delegation(
+    storage: &mut dyn Storage,
+    delegator: &Addr,
+) -> StdResult<Option<Addr>> {
+    let delegate = DELEGATIONS.may_load(storage, delegator.clone())?;
+    if let Some(delegate) = delegate.clone() {
+        let mut delegators = DELEGATORS.may_load(storage, delegate.clone())?.unwrap_or_default();
+        delegators.retain(|addr| addr != delegator);
+        if delegators.is_empty() {
+            DELEGATORS.remove(storage, delegate);
+        } else {
+            DELEGATORS.save(storage, delegate, &delegators)?;
+        }
+        DELEGATIONS.remove(storage, delegator.clone());
+    }
+
+    Ok(delegate)
+}
+
+fn get_delegation(
+    deps: Deps,
+    addr: String,
+) -> StdResult<DelegationResponse> {
+    let addr = deps.api.addr_validate(&addr)?;
+
+    Ok(DelegationResponse {
+        delegate: DELEGATIONS.may_load(deps.storage, addr.clone())?,
+        delegators: DELEGATORS.may_load(deps.storage, addr.clone())?.unwrap_or_default(),
+        addr,
+    })
+}
+
+/// Add a curator's rewards since their last update
+fn accrue_curator_rewards(
+    stats: &mut CuratorStats,
+    rewards: &CuratorRewards,
+) {
+    stats.pending_rewards += Uint128::new(stats.score as u128) * (rewards.reward_index - stats.reward_index);
+    stats.reward_index = rewards.reward_index;
+}
+
+/// Score the curators of a resolved submission, hits raise scores & misses lower them
+fn score_curators(
+    storage: &mut dyn Storage,
+    curators: &[Addr],
+    hit: bool,
+) -> StdResult<()> {
+    if curators.is_empty() {
+        return Ok(());
+    }
+    let mut rewards = CURATOR_REWARDS.may_load(storage)?.unwrap_or_default();
+    for curator in curators {
+        let mut stats = CURATOR_STATS.may_load(storage, curator.clone())?.unwrap_or_default();
+        //Rewards so far are at the old score
+        accrue_curator_rewards(&mut stats, &rewards);
+        if hit {
+            stats.hits += 1;
+            stats.score += 1;
+            rewards.total_score += 1;
+        } else {
+            stats.misses += 1;
+            if stats.score > 0 {
+                stats.score -= 1;
+                rewards.total_score -= 1;
+            }
+        }
+        CURATOR_STATS.save(storage, curator.clone(), &stats)?;
+    }
+    CURATOR_REWARDS.save(storage, &rewards)
+}
+
+/// Share rewards by curator score, returns the amount shared. Nothing is shared if no one has a score.
+fn share_curator_rewards(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    let mut rewards = CURATOR_REWARDS.may_load(storage)?.unwrap_or_default();
+    if rewards.total_score == 0 || amount.is_zero() {
+        return Ok(Uint128::zero());
+    }
+    rewards.reward_index += Decimal::from_ratio(amount, rewards.total_score);
+    rewards.unclaimed += amount;
+    CURATOR_REWARDS.save(storage, &rewards)?;
+
+    Ok(amount)
+}
+
+fn claim_curator_rewards(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let incentive_denom = config.incentive_denom
+        .ok_or_else(|| ContractError::CustomError { val: String::from("No incentive denom to reward curators with") })?;
+
+    let mut rewards = CURATOR_REWARDS.may_load(deps.storage)?.unwrap_or_default();
+    let mut stats = CURATOR_STATS.may_load(deps.storage, info.sender.clone())?.unwrap_or_default();
+    accrue_curator_rewards(&mut stats, &rewards);
+    let claimed = stats.pending_rewards;
+    if claimed.is_zero() {
+        return Err(ContractError::CustomError { val: String::from("No curator rewards to claim") });
+    }
+    stats.pending_rewards = Uint128::zero();
+    //Rounding leaves dust in unclaimed
+    rewards.unclaimed = rewards.unclaimed.saturating_sub(claimed);
+    CURATOR_STATS.save(deps.storage, info.sender.clone(), &stats)?;
+    CURATOR_REWARDS.save(deps.storage, &rewards)?;
+
+    Ok(Response::new()
+        .add_message(asset_transfer_msg(&incentive_denom, info.sender.to_string(), claimed)?)
+        .add_attribute("method", "claim_curator_rewards")
+        .add_attribute("curator", info.sender)
+        .add_attribute("claimed", claimed)
+    )
+}
+
+fn get_curator_stats(
+    deps: Deps,
+    addr: String,
+) -> StdResult<CuratorStatsResponse> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let rewards = CURATOR_REWARDS.may_load(deps.storage)?.unwrap_or_default();
+    let mut stats = CURATOR_STATS.may_load(deps.storage, addr.clone())?.unwrap_or_default();
+    accrue_curator_rewards(&mut stats, &rewards);
+
+    Ok(CuratorStatsResponse { addr, stats })
+}
+
+/// Update an artist's profile, creating it if needed
+fn update_artist_stats(
+    storage: &mut dyn Storage,
+    artist: &Addr,
+    update: impl FnOnce(&mut ArtistProfile),
+) -> StdResult<ArtistProfile> {
+    let mut profile = artist_profiles().may_load(storage, artist.clone())?.unwrap_or_default();
+    update(&mut profile);
+    artist_profiles().save(storage, artist.clone(), &profile)?;
+
+    Ok(profile)
+}
+
+/// The artist discount if the artist qualifies for it
+fn get_artist_discount(
+    deps: Deps,
+    config: &Config,
+    artist: &Addr,
+) -> StdResult<Option<ArtistDiscount>> {
+    let discount = match config.artist_discount.clone() {
+        Some(discount) => discount,
+        None => return Ok(None),
+    };
+    let sold = artist_profiles().may_load(deps.storage, artist.clone())?.unwrap_or_default().sold;
+    if sold < discount.min_sold {
+        return Ok(None);
+    }
+
+    Ok(Some(discount))
+}
+
+/// Submission cost for non-holders after the artist discount
+fn get_submission_cost(
+    deps: Deps,
+    config: &Config,
+    artist: &Addr,
+) -> StdResult<Uint128> {
+    let submission_cost = Uint128::new(config.submission_cost);
+    match get_artist_discount(deps, config, artist)? {
+        Some(discount) => Ok(submission_cost * (Decimal::one() - discount.submission_cost_discount)),
+        None => Ok(submission_cost),
+    }
+}
+
+/// Curation passing threshold after the artist discount
+fn get_artist_passing_threshold(
+    deps: Deps,
+    config: &Config,
+    artist: &Addr,
+    passing_threshold: u128,
+) -> StdResult<u128> {
+    match get_artist_discount(deps, config, artist)? {
+        Some(discount) => Ok((Uint128::new(passing_threshold) * (Decimal::one() - discount.curation_threshold_discount)).u128()),
+        None => Ok(passing_threshold),
+    }
+}
+
+fn get_artist_profile(
+    deps: Deps,
+    artist: String,
+) -> StdResult<ArtistProfileResponse> {
+    let artist = deps.api.addr_validate(&artist)?;
+    let profile = artist_profiles().may_load(deps.storage, artist.clone())?.unwrap_or_default();
+
+    Ok(ArtistProfileResponse { artist, profile })
+}
+
+fn get_artist_leaderboard(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> StdResult<ArtistProfilesResponse> {
+    //Continue below the last artist's (total_proceeds, address)
+    let end = match start_after {
+        Some(artist) => {
+            let artist = deps.api.addr_validate(&artist)?;
+            let profile = artist_profiles().load(deps.storage, artist.clone())?;
+            Some(Bound::exclusive((profile.total_proceeds.u128(), artist)))
+        },
+        None => None,
+    };
+
+    let profiles: StdResult<Vec<ArtistProfileResponse>> = artist_profiles()
+        .idx.total_proceeds
+        .range(deps.storage, None, end, Order::Descending)
+        .map(|item| item.map(|(artist, profile)| ArtistProfileResponse { artist, profile }))
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .collect();
+
+    Ok(ArtistProfilesResponse { profiles: profiles? })
+}
+
+fn update_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for addr in add.iter() {
+        ALLOWLIST.save(deps.storage, deps.api.addr_validate(addr)?, &())?;
+    }
+    for addr in remove.iter() {
+        ALLOWLIST.remove(deps.storage, deps.api.addr_validate(addr)?);
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "update_allowlist")
+        .add_attribute("added", to_json_string(&add)?)
+        .add_attribute("removed", to_json_string(&remove)?)
+    )
+}
+
+fn add_free_voter(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    addr: String,
+    weight: u64,
+    expiration: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if weight == 0 {
+        return Err(ContractError::CustomError { val: String::from("Free voter weight must be above 0") });
+    }
+    if let Some(expiration) = expiration {
+        if expiration <= env.block.time.seconds() {
+            return Err(ContractError::CustomError { val: String::from("Free voter expiration must be in the future") });
+        }
+    }
+
+    let addr = deps.api.addr_validate(&addr)?;
+    FREE_VOTERS.save(deps.storage, addr.clone(), &FreeVoter { weight, expiration })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_free_voter")
+        .add_attribute("addr", addr)
+        .add_attribute("weight", weight.to_string())
+        .add_attribute("expiration", to_json_string(&expiration)?)
+    )
+}
+
+fn remove_free_voter(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&addr)?;
+    if !FREE_VOTERS.has(deps.storage, addr.clone()) {
+        return Err(ContractError::CustomError { val: String::from("Address isn't a free voter") });
+    }
+    FREE_VOTERS.remove(deps.storage, addr.clone());
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_free_voter")
+        .add_attribute("addr", addr)
+    )
+}
+
+fn get_free_voters(
+    deps: Deps,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> StdResult<FreeVotersResponse> {
+    let start = match start_after {
+        Some(addr) => Some(Bound::exclusive(deps.api.addr_validate(&addr)?)),
+        None => None,
+    };
+
+    let voters: StdResult<Vec<FreeVoterResponse>> = FREE_VOTERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(addr, voter)| FreeVoterResponse { addr, voter }))
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .collect();
+
+    Ok(FreeVotersResponse { voters: voters? })
+}
+
+/// Errors if the bidder doesn't pass the gate
+fn assert_bid_gate(
+    deps: Deps,
+    config: &Config,
+    gate: &Option<BidGate>,
+    bidder: &Addr,
+) -> Result<(), ContractError> {
+    match gate {
+        Some(BidGate::Holders { min_nfts }) => {
+            let nfts = get_collection_nfts(deps, config.clone().sg721_addr, bidder)?;
+            if nfts < *min_nfts {
+                return Err(ContractError::CustomError { val: format!("Bidders must hold {} NFTs from the collection", min_nfts) });
+            }
+        },
+        Some(BidGate::Allowlist) if !ALLOWLIST.has(deps.storage, bidder.clone()) => {
+            return Err(ContractError::CustomError { val: String::from("Bidder isn't on the allowlist") });
+        },
+        Some(BidGate::IncentiveBalance { min_balance }) => {
+            let balance = match &config.incentive_denom {
+                Some(AssetInfo::NativeToken { denom }) => deps.querier.query_balance(bidder, denom)?.amount,
+                Some(AssetInfo::Token { address }) => {
+                    deps.querier.query::<BalanceResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+                        contract_addr: address.to_string(),
+                        msg: to_json_binary(&Cw20QueryMsg::Balance { address: bidder.to_string() })?,
+                    }))?.balance
+                },
+                None => Uint128::zero(),
+            };
+            if balance < *min_balance {
+                return Err(ContractError::CustomError { val: format!("Bidders must hold {} of the incentive asset", min_balance) });
+            }
+        },
+        //Allowlisted bidders & ungated auctions
+        _ => {},
+    }
+
+    Ok(())
+}
+
+/// Lowest bid that beats the highest bid by the minimum outbid
+fn get_minimum_outbid(
+    config: &Config,
+    highest_bid: Uint128,
+) -> Uint128 {
+    highest_bid * (Decimal::one() + config.minimum_outbid) + Uint128::one()
+}
+
+/// Escrowed by the live auction's highest bidder, their max if they're proxy bidding
+fn get_highest_bid_escrow(
+    storage: &dyn Storage,
+    live_auction: &Auction,
+) -> StdResult<Uint128> {
+    Ok(match PROXY_BID.may_load(storage)? {
+        Some(proxy_bid) => proxy_bid.max_bid,
+        None => Uint128::new(live_auction.highest_bid.amount),
+    })
+}
+
+fn bid_on_live_auction(
+    deps: DepsMut,
+    env: Env,
+    current_bid: Bid,
+    max_bid: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     //Load config
     let config = CONFIG.load(deps.storage)?;
-    //Assert funds are the bid asset
-    let current_bid = assert_bid_asset(&info, config.clone().bid_denom)?;
     //Initialize msgs
     let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut refund_amount = Uint128::zero();
 
     //This will be initiated in the instantiate function & refreshed at the end of the conclude_auction function
     let mut live_auction = match NFT_AUCTION.load(deps.storage){
@@ -535,51 +2085,130 @@ fn bid_on_live_auction(
         return Err(ContractError::CustomError { val: String::from("Auction has ended") });
     }
 
+    //Check if the bidder passes the auction's gate, or the config's if it has none
+    assert_bid_gate(deps.as_ref(), &config, &live_auction.bid_gate.clone().or(config.clone().bid_gate), &current_bid.bidder)?;
+
+    //Proxy bids escrow their max
+    let escrow = Uint128::new(current_bid.amount);
+    if let Some(max_bid) = max_bid {
+        if max_bid != escrow {
+            return Err(ContractError::CustomError { val: String::from("Proxy bids must send the max bid") });
+        }
+    }
+
+    //The highest bidder bidding again only raises their max, the price stays until they're outbid
+    if live_auction.bid_count > 0 && current_bid.bidder == live_auction.highest_bid.bidder {
+        let leader_escrow = get_highest_bid_escrow(deps.storage, &live_auction)?;
+        if escrow <= leader_escrow {
+            return Err(ContractError::CustomError { val: String::from("Bid must be higher than your current max bid") });
+        }
+        PROXY_BID.save(deps.storage, &ProxyBid { bidder: current_bid.bidder.clone(), max_bid: escrow })?;
+
+        //Send the previous escrow back
+        msgs.push(asset_transfer_msg(&config.bid_denom, current_bid.bidder.to_string(), leader_escrow)?);
+        let mut treasury = TREASURY.load(deps.storage)?;
+        treasury.escrowed_bids = treasury.escrowed_bids + escrow - leader_escrow;
+        TREASURY.save(deps.storage, &treasury)?;
+
+        return Ok(Response::new()
+            .add_messages(msgs)
+            .add_attribute("method", "bid_on_live_auction")
+            .add_attribute("bidder", current_bid.bidder)
+            .add_attribute("max_bid", escrow.to_string())
+            .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
+        );
+    }
+
     //Check if the bid is higher than the current highest bid
-    if let Some(highest_bid) = live_auction.bids.clone().last() {
-        if Uint128::new(current_bid.amount) <= Uint128::new(highest_bid.amount) * (Decimal::one() + config.minimum_outbid) {
+    let minimum_bid = if live_auction.bid_count == 0 {
+        //The first bid must meet the reserve price
+        if escrow < Uint128::new(live_auction.reserve_price) {
+            return Err(ContractError::CustomError { val: String::from("Bid is lower than the reserve price") });
+        }
+        Uint128::new(live_auction.reserve_price).max(Uint128::one())
+    } else {
+        let minimum_bid = get_minimum_outbid(&config, Uint128::new(live_auction.highest_bid.amount));
+        if escrow < minimum_bid {
             return Err(ContractError::CustomError { val: String::from("Bid is lower than the minimum outbid amount") });
-        } else {
-            //Add the bid to the auction's bid list
-            live_auction.bids.push(current_bid.clone());
+        }
+        minimum_bid
+    };
 
-            //Send the previous highest bid back to the bidder
-            msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: highest_bid.bidder.to_string(),
-                    amount: vec![Coin {
-                        denom: config.bid_denom,
-                        amount: Uint128::new(highest_bid.amount),
-                    }],
-                }));
-
-            //Set bid as highest bid
-            live_auction.highest_bid = current_bid.clone();
-        }
-    } else if live_auction.bids.is_empty() {
-        //Add the bid to the auction's bid list
-        live_auction.bids.push(current_bid.clone());
-        //Set bid as highest bid
-        live_auction.highest_bid = current_bid.clone();
+    //Bids added to the auction's bid list
+    let mut new_bids: Vec<Bid> = vec![];
+    let leader = live_auction.highest_bid.bidder.clone();
+    let leader_escrow = get_highest_bid_escrow(deps.storage, &live_auction)?;
+    if live_auction.bid_count > 0 && leader_escrow >= escrow {
+        //The highest bidder's proxy counters, ties go to the earlier bid
+        let counter_bid = get_minimum_outbid(&config, escrow).min(leader_escrow);
+        new_bids.push(Bid { bidder: current_bid.bidder.clone(), amount: escrow.u128() });
+        new_bids.push(Bid { bidder: leader, amount: counter_bid.u128() });
+
+        //Send the bid back to the bidder
+        msgs.push(asset_transfer_msg(&config.bid_denom, current_bid.bidder.to_string(), escrow)?);
+        refund_amount = escrow;
+    } else {
+        if live_auction.bid_count > 0 {
+            //The highest bidder's proxy counters up to its max
+            if leader_escrow > Uint128::new(live_auction.highest_bid.amount) {
+                new_bids.push(Bid { bidder: leader.clone(), amount: leader_escrow.u128() });
+            }
+            //Send the previous highest bidder's escrow back
+            msgs.push(asset_transfer_msg(&config.bid_denom, leader.to_string(), leader_escrow)?);
+            refund_amount = leader_escrow;
+        }
+        //Proxy bids only go as high as needed to lead
+        let amount = match max_bid {
+            Some(_) if live_auction.bid_count > 0 => get_minimum_outbid(&config, leader_escrow).min(escrow),
+            Some(_) => minimum_bid,
+            None => escrow,
+        };
+        new_bids.push(Bid { bidder: current_bid.bidder.clone(), amount: amount.u128() });
+
+        //Save the new highest bidder's max
+        match max_bid {
+            Some(max_bid) => PROXY_BID.save(deps.storage, &ProxyBid { bidder: current_bid.bidder.clone(), max_bid })?,
+            None => PROXY_BID.remove(deps.storage),
+        }
+    }
+    //Add the bids to the auction's bid history & set the highest bid
+    for bid in new_bids.iter() {
+        BIDS.save(deps.storage, (live_auction.auction_id, live_auction.bid_count), bid)?;
+        live_auction.bid_count += 1;
+    }
+    if let Some(highest_bid) = new_bids.last() {
+        live_auction.highest_bid = highest_bid.clone();
     }
     NFT_AUCTION.save(deps.storage, &live_auction)?;
+    //Update escrow, refunds are the previous highest bidder's escrow or the outbid bid
+    let mut treasury = TREASURY.load(deps.storage)?;
+    treasury.escrowed_bids = treasury.escrowed_bids + escrow - refund_amount;
+    TREASURY.save(deps.storage, &treasury)?;
+
+    let mut events: Vec<Event> = vec![];
+    for bid in new_bids.iter() {
+        events.push(bid_event("nft", &bid.bidder, bid.amount, &config.bid_denom, live_auction.auction_end_time)?);
+    }
+    let placed_bid = new_bids.iter().find(|bid| bid.bidder == current_bid.bidder).map(|bid| bid.amount).unwrap_or(current_bid.amount);
 
     Ok(Response::new()
         .add_messages(msgs)
+        .add_events(events)
         .add_attribute("method", "bid_on_live_auction")
-        .add_attribute("bidder", info.sender)
-        .add_attribute("bid", current_bid.amount.to_string())
+        .add_attribute("bidder", current_bid.bidder)
+        .add_attribute("bid", placed_bid.to_string())
+        .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
     )
 }
 
 /// These auctions last as long as the current NFT auction is live & concludes with it
 fn bid_for_bid_assets(
     deps: DepsMut,
-    info: MessageInfo,
+    env: Env,
+    current_bid: Bid,
 ) -> Result<Response, ContractError> {
     //Load config
     let config = CONFIG.load(deps.storage)?;
-    //Assert funds are the incentive
-    let current_bid = assert_bid_asset(&info, config.clone().incentive_denom.unwrap())?;//unwrap is safe because these don't exist without a incentive denom
     //Initialize msgs
     let mut msgs: Vec<CosmosMsg> = vec![];
 
@@ -590,111 +2219,357 @@ fn bid_for_bid_assets(
     
     };
 
+    //Check if the auction is still live
+    if env.block.time.seconds() >= live_auction.auction_end_time {
+        return Err(ContractError::CustomError { val: String::from("Bid asset auction has ended") });
+    }
+
     //Check if the bid is higher than the current highest bid
-    if Uint128::new(current_bid.amount) < Uint128::new(live_auction.highest_bid.amount) * (Decimal::one() + config.minimum_outbid){
+    if current_bid.amount == 0 || Uint128::new(current_bid.amount) < Uint128::new(live_auction.highest_bid.amount) * (Decimal::one() + config.minimum_outbid){
         return Err(ContractError::CustomError { val: String::from("Bid is lower than the minimum outbid amount") });
     } else {
         //Send the previous highest bid back to the bidder
         if live_auction.highest_bid.amount > 0 {
-            msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: live_auction.highest_bid.bidder.to_string(),
-                    amount: vec![Coin {
-                        denom: config.incentive_denom.unwrap(), //These auctions don't happen without a denom so its safe to unwrap
-                        amount: Uint128::new(live_auction.highest_bid.amount),
-                    }],
-                }));
+            msgs.push(asset_transfer_msg(
+                &config.clone().incentive_denom.unwrap(), //These auctions don't happen without a denom so its safe to unwrap
+                live_auction.highest_bid.bidder.to_string(),
+                Uint128::new(live_auction.highest_bid.amount),
+            )?);
         }
 
         //Set bid as highest bid
         live_auction.highest_bid = current_bid.clone();
+
+        //Soft close: bids close to the end extend the auction
+        let soft_close_time = env.block.time.seconds() + config.soft_close_period;
+        if soft_close_time > live_auction.auction_end_time {
+            live_auction.auction_end_time = soft_close_time;
+        }
     }
     ////Save the new bid asset auction
     ASSET_AUCTION.save(deps.storage, &live_auction)?;
 
     Ok(Response::new()
         .add_messages(msgs)
+        .add_event(bid_event("asset", &current_bid.bidder, current_bid.amount, &config.incentive_denom.unwrap(), live_auction.auction_end_time)?)
         .add_attribute("method", "bid_for_bid_assets")
-        .add_attribute("bidder", info.sender)
+        .add_attribute("bidder", current_bid.bidder)
         .add_attribute("new_highest_bid", current_bid.amount.to_string())
+        .add_attribute("auction_end_time", live_auction.auction_end_time.to_string())
+    )
+}
+
+/// Add an asset to a basket, merging it with the same asset
+fn add_to_basket(
+    basket: &mut Vec<Asset>,
+    asset: Asset,
+) {
+    if asset.amount.is_zero() {
+        return;
+    }
+    match basket.iter_mut().find(|basket_asset| basket_asset.info == asset.info) {
+        Some(basket_asset) => basket_asset.amount += asset.amount,
+        None => basket.push(asset),
+    }
+}
+
+/// Assets the next bid asset auction can hold: the pool, royalties, pending donations & the live auction's assets if they roll over
+fn get_next_auction_basket(
+    storage: &dyn Storage,
+    config: &Config,
+    treasury: &Treasury,
+) -> StdResult<Vec<Asset>> {
+    //The pool & royalties hold their spot before they're funded
+    let mut basket: Vec<Asset> = vec![Asset { info: config.bid_denom.clone(), amount: Uint128::one() }];
+    if config.royalty_policy == RoyaltyPolicy::BidAssetAuction {
+        add_to_basket(&mut basket, Asset { info: AssetInfo::NativeToken { denom: config.royalty_denom.clone() }, amount: Uint128::one() });
+    }
+    for donation in treasury.donations.clone() {
+        add_to_basket(&mut basket, donation.asset);
+    }
+    //Unbid assets roll over
+    if let Some(auction) = ASSET_AUCTION.may_load(storage)? {
+        if auction.highest_bid.amount == 0 {
+            for asset in auction.auctioned_asset {
+                add_to_basket(&mut basket, asset);
+            }
+        }
+    }
+
+    Ok(basket)
+}
+
+/// Donations are added to the next bid asset auction
+fn donate(
+    deps: DepsMut,
+    donor: Addr,
+    assets: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut treasury = TREASURY.load(deps.storage)?;
+
+    if assets.iter().all(|asset| asset.amount.is_zero()) {
+        return Err(ContractError::InvalidAsset { asset: String::from("No assets donated") });
+    }
+
+    //Limit the amount of assets in the basket
+    let mut basket = get_next_auction_basket(deps.storage, &config, &treasury)?;
+    for asset in assets.clone() {
+        if !basket.iter().any(|basket_asset| basket_asset.info == asset.info) && basket.len() >= MAX_AUCTIONED_ASSETS {
+            return Err(ContractError::InvalidAsset { asset: format!("Bid asset auctions are limited to {} assets", MAX_AUCTIONED_ASSETS) });
+        }
+        add_to_basket(&mut basket, asset.clone());
+        
+        //Add to the donor's pending donation
+        match treasury.donations.iter_mut().find(|donation| donation.donor == donor && donation.asset.info == asset.info) {
+            Some(donation) => donation.asset.amount += asset.amount,
+            None => treasury.donations.push(Donation {
+                donor: donor.clone(),
+                asset: asset.clone(),
+            }),
+        }
+    }
+    TREASURY.save(deps.storage, &treasury)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "donate")
+        .add_attribute("donor", donor)
+        .add_attribute("assets", to_json_string(&assets)?)
+    )
+}
+
+/// Conclude the bid asset auction after it ends, or start one if there is none
+fn settle_bid_asset_auction(
+    deps: DepsMut,
+    env: Env,
+) -> Result<Response, ContractError> {
+    if let Some(auction) = ASSET_AUCTION.may_load(deps.storage)? {
+        if env.block.time.seconds() < auction.auction_end_time {
+            return Err(ContractError::CustomError { val: String::from("Bid asset auction is still live") });
+        }
+    }
+    let (msgs, event) = conclude_bid_asset_auction(deps.storage, env)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_event(event)
+        .add_attribute("method", "conclude_bid_asset_auction")
     )
 }
 
+/// Conclude the bid asset auction if it has ended, or start one if there is none
+fn try_conclude_bid_asset_auction(
+    storage: &mut dyn Storage,
+    env: Env,
+) -> Result<(Vec<CosmosMsg>, Vec<Event>), ContractError> {
+    match ASSET_AUCTION.may_load(storage)? {
+        Some(auction) if env.block.time.seconds() < auction.auction_end_time => Ok((vec![], vec![])),
+        _ => {
+            let (msgs, event) = conclude_bid_asset_auction(storage, env)?;
+            Ok((msgs, vec![event]))
+        },
+    }
+}
+
 //End & Start new Bid Asset Auction
 fn conclude_bid_asset_auction(
     storage: &mut dyn Storage,
-    querier: QuerierWrapper,
     env: Env,
-    recipient_send_amount: Uint128,
-) -> Result<Vec<CosmosMsg>, ContractError> {
+) -> Result<(Vec<CosmosMsg>, Event), ContractError> {
     //Initialize msgs
     let mut msgs: Vec<CosmosMsg> = vec![];
-    //Load live auction
-    let live_auction = ASSET_AUCTION.load(storage);
+    //Settlement info for the event
+    let mut winner: Option<Addr> = None;
+    let mut winning_bid = 0u128;
+    let mut sold: Vec<Asset> = vec![];
+    let mut burned: Option<Asset> = None;
+    let mut curator_rewards = Uint128::zero();
     //Load config
     let config = CONFIG.load(storage)?;
+    //Load ledgers
+    let mut treasury = TREASURY.load(storage)?;
     
-    //Query contract's balance to include any submission costs to the bid asset auction
-    let bid_denom_balance = querier.query_balance(env.clone().contract.address, config.bid_denom.clone())?;
-    let asset_bid_amount = match bid_denom_balance.amount.checked_sub(recipient_send_amount){
-        Ok(amount) => amount,
-        //This helps pass contract tests, its not actually possible to have less assets then what was sent
-        //If it does happen, the BankMsg::Send will fail
-        Err(_) => Uint128::new(0)
-        //Set to 10000000 to pass contract_test
-    
-    };
-    let mut new_auction_asset = Coin {
-        denom: config.bid_denom.clone(),
-        amount: asset_bid_amount,
-    };
+    //Pool submission costs, incentive share & donations for the next auction
+    let mut new_auction_asset: Vec<Asset> = vec![];
+    add_to_basket(&mut new_auction_asset, Asset {
+        info: config.bid_denom.clone(),
+        amount: treasury.pool(),
+    });
+    for donation in treasury.donations.clone() {
+        add_to_basket(&mut new_auction_asset, donation.asset);
+    }
     
-    match live_auction {
-        Ok(auction) => {
-            //End the auction & distribute the asset to the highest bidder
-            //If no one bids, the assets are sent to the contract
-            if !auction.auctioned_asset.amount.is_zero() {
-                msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: auction.highest_bid.bidder.to_string(),
-                    amount: vec![auction.clone().auctioned_asset],
-                }));
-
-                //Subtract the auctioned asset from the new auction asset
-                //to cover the overage from the queried balance
-                new_auction_asset.amount -= auction.auctioned_asset.amount;
+    if let Some(auction) = ASSET_AUCTION.may_load(storage)? {
+        if auction.highest_bid.amount > 0 {
+            //End the auction & distribute the assets to the highest bidder
+            for asset in auction.auctioned_asset.clone() {
+                if !asset.amount.is_zero() {
+                    msgs.push(asset_transfer_msg(&asset.info, auction.highest_bid.bidder.to_string(), asset.amount)?);
+                }
             }
-            if auction.highest_bid.amount > 0 {
-                //Send the bid to the burn address
-                msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: String::from("stars1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq8lhzvv"),
-                    amount: vec![Coin {
-                        denom: config.incentive_denom.unwrap(), //These auctions don't happen without a denom so its safe to unwrap
-                        amount: Uint128::new(auction.highest_bid.amount),
-                    
-                    }],
-                }));
+            //Share part of the bid with curators & burn the rest
+            let bid = Uint128::new(auction.highest_bid.amount);
+            curator_rewards = share_curator_rewards(storage, bid * config.curator_reward_percent)?;
+            let burn = Asset {
+                info: config.clone().incentive_denom.unwrap(), //These auctions don't happen without a denom so its safe to unwrap
+                amount: bid - curator_rewards,
+            };
+            if !burn.amount.is_zero() {
+                msgs.push(burn_asset(storage, &env, &config.burn_strategy, burn.clone())?);
             }
-            //Remove the auction
-            ASSET_AUCTION.remove(storage);
-        },
-        Err(_) => {
-            //Just start the next auction below
-        },
-    };
 
-    if new_auction_asset.amount.u128() > 0 {
+            winner = Some(auction.highest_bid.bidder);
+            winning_bid = auction.highest_bid.amount;
+            sold = auction.auctioned_asset;
+            burned = Some(burn);
+        } else {
+            //If no one bids, the assets roll over to the next auction
+            for asset in auction.auctioned_asset {
+                add_to_basket(&mut new_auction_asset, asset);
+            }
+        }
+        //Remove the auction
+        ASSET_AUCTION.remove(storage);
+    }
+
+    //Ledgers are now in the auction, escrowed submission fees wait for their submissions
+    treasury.submission_fees = Uint128::zero();
+    treasury.incentive_share = Uint128::zero();
+    treasury.donations = vec![];
+    TREASURY.save(storage, &treasury)?;
+
+    let mut next_auction_end_time = 0u64;
+    if !new_auction_asset.is_empty() {
         //Start the new auction
+        next_auction_end_time = env.block.time.seconds() + (SECONDS_PER_DAY * config.asset_auction_period);
         ASSET_AUCTION.save(storage, &BidAssetAuction {
-            auctioned_asset: new_auction_asset,
+            auctioned_asset: new_auction_asset.clone(),
             highest_bid: Bid {
                 bidder: env.contract.address,
                 amount: 0u128,
             },
+            auction_end_time: next_auction_end_time,
         })?;
     }
 
-    Ok(msgs)
+    let event = asset_auction_settled_event(winner.as_ref(), winning_bid, &sold, burned.as_ref(), curator_rewards, &new_auction_asset, next_auction_end_time)?;
+
+    Ok((msgs, event))
+}
+
+
+/// Replace the live NFT auction with the next pending auction, or remove it if there is none.
+/// Returns the state the loop moves to, callers save it.
+fn start_next_auction(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> StdResult<AuctionState> {
+    let mut pending_auctions = PENDING_AUCTION.load(storage)?;
+    if !pending_auctions.is_empty() {
+        //Get the next auction
+        let mut next_auction = pending_auctions.remove(0);
+        //set auction id, end time & reserve price
+        next_auction.auction_id = get_next_auction_id(storage)?;
+        next_auction.auction_end_time = env.block.time.seconds() + (SECONDS_PER_DAY * config.auction_period);
+        next_auction.reserve_price = config.reserve_price;
+        //Save as live auction
+        NFT_AUCTION.save(storage, &next_auction)?;
+        //Save the pending auctions
+        PENDING_AUCTION.save(storage, &pending_auctions)?;
+        
+        Ok(AuctionState::Live)
+    } else {        
+        //Remove the concluded auction
+        NFT_AUCTION.remove(storage);    
+
+        Ok(AuctionState::Idle)
+    }
+}
+
+/// Save the auction state, returns a transition event if it changed
+fn set_auction_state(
+    storage: &mut dyn Storage,
+    state: AuctionState,
+) -> StdResult<Vec<Event>> {
+    let previous_state = AUCTION_STATE.may_load(storage)?.unwrap_or(AuctionState::Idle);
+    if previous_state == state {
+        return Ok(vec![]);
+    }
+    AUCTION_STATE.save(storage, &state)?;
+
+    Ok(vec![auction_state_event(&previous_state, &state)])
 }
 
+/// Permissionless, advances whatever is due & errors if nothing is
+fn crank(
+    mut deps: DepsMut,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let conclusion_paused = PAUSED.load(deps.storage)?.contains(&PauseScope::Conclusion);
+    let mut response = Response::new();
+    let mut advanced: Vec<&str> = vec![];
+
+    if !conclusion_paused {
+        match NFT_AUCTION.may_load(deps.storage)? {
+            //Settle the NFT auction, this concludes the bid asset auction if its due
+            Some(auction) if env.block.time.seconds() >= auction.auction_end_time => {
+                let res = conclude_auction(deps.branch(), env.clone())?;
+                response = response
+                    .add_submessages(res.messages)
+                    .add_events(res.events)
+                    .add_attributes(res.attributes);
+                advanced.push("settle");
+            },
+            Some(_) => {},
+            //Start the next auction if there are pending auctions without a live one
+            None => {
+                if !PENDING_AUCTION.load(deps.storage)?.is_empty() {
+                    let next_state = start_next_auction(deps.storage, &env, &config)?;
+                    response = response.add_events(set_auction_state(deps.storage, next_state)?);
+                    advanced.push("start_next");
+                }
+            },
+        }
+
+        //Settle the bid asset auction, or start one if there are assets to auction
+        let bid_asset_auction_due = match ASSET_AUCTION.may_load(deps.storage)? {
+            Some(auction) => env.block.time.seconds() >= auction.auction_end_time,
+            None => {
+                let treasury = TREASURY.load(deps.storage)?;
+                !treasury.pool().is_zero() || !treasury.donations.is_empty()
+            },
+        };
+        if bid_asset_auction_due {
+            let (msgs, event) = conclude_bid_asset_auction(deps.storage, env.clone())?;
+            response = response
+                .add_messages(msgs)
+                .add_event(event);
+            advanced.push("settle_bid_asset_auction");
+        }
+    }
+
+    //Prune expired submissions
+    let passing_threshold = get_passing_threshold(deps.as_ref(), &config)?;
+    let pruned = prune_expired_submissions(deps.storage, &env, passing_threshold)?;
+    if pruned > 0 {
+        CONFIG.update(deps.storage, |mut config| -> StdResult<Config> {
+            config.submission_total -= pruned;
+            Ok(config)
+        })?;
+        advanced.push("prune");
+    }
+
+    if advanced.is_empty() {
+        return Err(ContractError::CustomError { val: String::from("Nothing to crank") });
+    }
+
+    Ok(response
+        .add_attribute("method", "crank")
+        .add_attribute("advanced", to_json_string(&advanced)?)
+    )
+}
 
 fn conclude_auction(
     deps: DepsMut,
@@ -705,6 +2580,7 @@ fn conclude_auction(
     //Initialize msgs
     let mut msgs: Vec<CosmosMsg> = vec![];
     let mut sub_msgs: Vec<SubMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
     //Load live auction
     let mut live_auction = NFT_AUCTION.load(deps.storage)?;
 
@@ -715,12 +2591,48 @@ fn conclude_auction(
 
     //Mint the NFT & send the bid to the proceed_recipient
     if live_auction.highest_bid.amount > 0 {
+        //Pay the mint fee from the configured source, falling back to the reserve & then a ustars winning bid
+        let mint_fee = Uint128::new(config.mint_cost);
+        let winning_bid = Uint128::new(live_auction.highest_bid.amount);
+        let bid_covers_fee = config.bid_denom == (AssetInfo::NativeToken { denom: String::from("ustars") })
+            && winning_bid >= mint_fee;
+        let mut fee_from_bid = match config.mint_fee_source {
+            MintFeeSource::Reserve => false,
+            MintFeeSource::WinningBid => bid_covers_fee,
+        };
+        if !fee_from_bid {
+            let mint_reserve = MINT_RESERVE.may_load(deps.storage)?.unwrap_or_default();
+            if mint_reserve >= mint_fee {
+                let mint_reserve = mint_reserve - mint_fee;
+                MINT_RESERVE.save(deps.storage, &mint_reserve)?;
+                //Warn when the reserve is running low
+                let mints_remaining = mint_reserve.u128().checked_div(config.mint_cost).unwrap_or(0) as u64;
+                if !mint_fee.is_zero() && mints_remaining < config.low_reserve_mints {
+                    events.push(mint_reserve_low_event(mint_reserve, mints_remaining));
+                }
+            } else if bid_covers_fee {
+                //A short reserve doesn't block ustars conclusions
+                fee_from_bid = true;
+                events.push(mint_reserve_low_event(mint_reserve, 0));
+            } else {
+                return Err(ContractError::CustomError { val: String::from("Mint reserve can't cover the mint fee, use FundMintReserve & conclude again") });
+            }
+        }
+        let mut sale_amount = winning_bid;
+        if fee_from_bid {
+            sale_amount -= mint_fee;
+        }
+
+        //Submissions with metadata mint its JSON as the token_uri
+        let token_uri = match &live_auction.submission_info.metadata {
+            Some(metadata) => metadata.to_nft_metadata(&live_auction.submission_info.submission.token_uri).to_token_uri()?,
+            None => live_auction.submission_info.submission.token_uri.clone(),
+        };
+
         //Mint the NFT to the contract && set as a submsg to transfer to the winning bidder
         let msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: config.clone().minter_addr,
-            msg: to_json_binary(&BaseMinterExecuteMsg::Mint {
-                token_uri: live_auction.submission_info.submission.token_uri.clone(),
-            })?,
+            msg: to_json_binary(&BaseMinterExecuteMsg::Mint { token_uri })?,
             funds: vec![
                 Coin {
                     denom: String::from("ustars"),
@@ -728,27 +2640,63 @@ fn conclude_auction(
                 }],
         });
         sub_msgs.push(SubMsg::reply_on_success(msg, MINT_REPLY_ID));
+
         //Save winning bidder for transfer msg
         WINNING_BIDDER.save(deps.storage, &live_auction.highest_bid.bidder.to_string())?;
+        if let Some(submission_id) = live_auction.submission_id {
+            SUBMISSION_OUTCOMES.save(deps.storage, submission_id, &SubmissionStatus::Settled { winning_bid: live_auction.highest_bid.amount })?;
+            pool_submission_fee(deps.storage, submission_id)?;
+        }
+        //Add a royalty share for the artist
+        ROYALTY_SHARES.update(deps.storage, live_auction.submission_info.submission.proceed_recipient.clone(), |shares| -> StdResult<u64> {
+            Ok(shares.unwrap_or(0) + 1)
+        })?;
 
         //////Split the highest bid to the proceed_recipient & asset auction////
         if config.incentive_denom.is_none() {
             config.incentive_bid_percent = Decimal::percent(0);
         }
-        let recipient_send_amount = Uint128::new(live_auction.highest_bid.amount) * (Decimal::one() - config.incentive_bid_percent);        
+        let recipient_send_amount = sale_amount * (Decimal::one() - config.incentive_bid_percent);        
         if !recipient_send_amount.is_zero() {
-            msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: live_auction.submission_info.submission.proceed_recipient.to_string(),
-                amount: vec![Coin {
-                    denom: config.clone().bid_denom,
-                    amount: recipient_send_amount,
-                }],
-            }));
+            msgs.push(asset_transfer_msg(&config.bid_denom, live_auction.submission_info.submission.proceed_recipient.to_string(), recipient_send_amount)?);
         }        
+        update_artist_stats(deps.storage, &live_auction.submission_info.submission.submitter, |profile| {
+            profile.sold += 1;
+            profile.total_proceeds += recipient_send_amount;
+        })?;
+        //Score the curators before the bid asset auction shares rewards
+        score_curators(deps.storage, &live_auction.submission_info.curators, live_auction.highest_bid.amount > config.curator_hit_price)?;
+
+        //Refund the winner's unused proxy escrow
+        let escrow = get_highest_bid_escrow(deps.storage, &live_auction)?;
+        PROXY_BID.remove(deps.storage);
+        let unused_escrow = escrow - Uint128::new(live_auction.highest_bid.amount);
+        if !unused_escrow.is_zero() {
+            msgs.push(asset_transfer_msg(&config.bid_denom, live_auction.highest_bid.bidder.to_string(), unused_escrow)?);
+        }
 
-        //Conclude the current Bid Asset Auction
+        //Move the bid from escrow, the incentive share goes to the next bid asset auction
+        let incentive_share = sale_amount - recipient_send_amount;
+        TREASURY.update(deps.storage, |mut treasury| -> StdResult<Treasury> {
+            treasury.escrowed_bids -= escrow;
+            treasury.incentive_share += incentive_share;
+            Ok(treasury)
+        })?;
+        events.push(auction_settled_event(
+            &live_auction.submission_info.submission.token_uri,
+            &live_auction.submission_info.submission.proceed_recipient,
+            &live_auction.highest_bid.bidder,
+            live_auction.highest_bid.amount,
+            recipient_send_amount,
+            incentive_share,
+            live_auction.auction_end_time,
+        ));
+
+        //Conclude the current Bid Asset Auction if it has ended
         //Initiate the next Bid Asset Auction        
-        msgs.extend(conclude_bid_asset_auction(deps.storage, deps.querier, env.clone(), recipient_send_amount)?);
+        let (asset_msgs, asset_events) = try_conclude_bid_asset_auction(deps.storage, env.clone())?;
+        msgs.extend(asset_msgs);
+        events.extend(asset_events);
         //////
         
         // /////Send incentives to Bidders & curators
@@ -786,12 +2734,78 @@ fn conclude_auction(
         //     }
         // }
     } else {
-        //If no one bids, extend the auction time by 1 day
-        live_auction.auction_end_time += SECONDS_PER_DAY;
-        //Save the auction
-        NFT_AUCTION.save(deps.storage, &live_auction)?;
+        //If no one bids, apply the no-bid policy
+        let max_extensions = match config.no_bid_policy {
+            NoBidPolicy::Rotate { max_extensions } | NoBidPolicy::Drop { max_extensions } => Some(max_extensions),
+            NoBidPolicy::LowerReserve { .. } => None,
+        };
+        let mut refund = Uint128::zero();
+        let can_extend = match max_extensions {
+            Some(max_extensions) => live_auction.extensions < max_extensions,
+            None => true,
+        };
+        let action = if can_extend {
+            //Extend the auction time by 1 day
+            live_auction.auction_end_time += SECONDS_PER_DAY;
+            live_auction.extensions += 1;
+            if let NoBidPolicy::LowerReserve { decrease } = config.no_bid_policy {
+                live_auction.reserve_price = (Uint128::new(live_auction.reserve_price) * (Decimal::one() - decrease)).u128();
+            }
+            //Save the auction
+            NFT_AUCTION.save(deps.storage, &live_auction)?;
+            "extend"
+        } else if let NoBidPolicy::Rotate { .. } = config.no_bid_policy {
+            //Move to the back of the pending auctions, restarts if there are none.
+            //The next auction is taken from the front, so the queue doesn't grow past PENDING_AUCTION_LIMIT.
+            PENDING_AUCTION.update(deps.storage, |mut auctions| -> StdResult<Vec<Auction>> {
+                auctions.push(Auction {
+                    auction_id: 0, //will set when active
+                    bid_count: 0,
+                    auction_end_time: 0, //will set when active
+                    extensions: 0,
+                    ..live_auction.clone()
+                });
+                Ok(auctions)
+            })?;
+            let next_state = start_next_auction(deps.storage, &env, &config)?;
+            events.extend(set_auction_state(deps.storage, next_state)?);
+            "rotate"
+        } else {
+            //Drop the submission & refund the submitter's escrowed fee
+            if let Some(submission_id) = live_auction.submission_id {
+                SUBMISSION_OUTCOMES.save(deps.storage, submission_id, &SubmissionStatus::Unsold)?;
+                score_curators(deps.storage, &live_auction.submission_info.curators, false)?;
+                if let Some(fee) = SUBMISSION_FEES.may_load(deps.storage, submission_id)? {
+                    SUBMISSION_FEES.remove(deps.storage, submission_id);
+                    let mut treasury = TREASURY.load(deps.storage)?;
+                    refund = fee;
+                    treasury.escrowed_submission_fees -= refund;
+                    TREASURY.save(deps.storage, &treasury)?;
+                }
+            }
+            if !refund.is_zero() {
+                msgs.push(asset_transfer_msg(&config.bid_denom, live_auction.submission_info.submission.submitter.to_string(), refund)?);
+            }
+            let next_state = start_next_auction(deps.storage, &env, &config)?;
+            events.extend(set_auction_state(deps.storage, next_state)?);
+            "drop"
+        };
+        events.insert(0, no_bid_event(
+            &live_auction.submission_info.submission.token_uri,
+            action,
+            live_auction.extensions,
+            live_auction.reserve_price,
+            refund,
+            if action == "extend" { live_auction.auction_end_time } else { 0 },
+        ));
+        //The bid asset auction runs on its own timer
+        let (asset_msgs, asset_events) = try_conclude_bid_asset_auction(deps.storage, env.clone())?;
+        msgs.extend(asset_msgs);
+        events.extend(asset_events);
 
         return Ok(Response::new()
+            .add_messages(msgs)
+            .add_events(events)
             .add_attribute("method", "conclude_auction")
             .add_attribute("highest_bidder", "None")
             .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
@@ -799,24 +2813,13 @@ fn conclude_auction(
     }
 
     //Set the new auction to the next pending auction
-    let mut pending_auctions = PENDING_AUCTION.load(deps.storage)?;
-    if !pending_auctions.is_empty() {
-        //Get the next auction
-        let mut next_auction = pending_auctions.remove(0);
-        //set auction end time
-        next_auction.auction_end_time = env.block.time.seconds() + (SECONDS_PER_DAY * config.auction_period);
-        //Save as live auction
-        NFT_AUCTION.save(deps.storage, &next_auction)?;
-        //Save the pending auctions
-        PENDING_AUCTION.save(deps.storage, &pending_auctions)?;
-    } else {        
-        //Remove the concluded auction
-        NFT_AUCTION.remove(deps.storage);    
-    }
+    let next_state = start_next_auction(deps.storage, &env, &config)?;
+    events.extend(set_auction_state(deps.storage, next_state)?);
 
     Ok(Response::new()
         .add_submessages(sub_msgs)
         .add_messages(msgs)
+        .add_events(events)
         .add_attribute("method", "conclude_auction")
         .add_attribute("highest_bidder", live_auction.highest_bid.bidder)
         .add_attribute("highest_bid", live_auction.highest_bid.amount.to_string())
@@ -827,13 +2830,130 @@ fn conclude_auction(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary((&CONFIG.load(deps.storage)?)),
-        QueryMsg::LiveNftAuction {  } => to_json_binary(&NFT_AUCTION.load(deps.storage)?),
-        QueryMsg::LiveBidAssetAuction {  } => to_json_binary(&ASSET_AUCTION.load(deps.storage)?),
+        QueryMsg::BidHistory { auction_id, limit, start_after } => to_json_binary(&get_bid_history(deps, auction_id, limit, start_after)?),
+        QueryMsg::LiveNftAuction {  } => to_json_binary(&get_live_nft_auction(deps, env)?),
+        QueryMsg::LiveBidAssetAuction {  } => to_json_binary(&get_live_bid_asset_auction(deps, env)?),
         QueryMsg::PendingAuctions { limit, start_after } => to_json_binary(&get_pending_auctions(deps, limit, start_after)?),
         QueryMsg::Submissions { submission_id, limit, start_after } => to_json_binary(&get_submissions(deps, submission_id, limit, start_after)?),
+        QueryMsg::Burned {  } => to_json_binary(&get_burned(deps)?),
+        QueryMsg::Treasury {  } => to_json_binary(&get_treasury(deps)?),
+        QueryMsg::Paused {  } => to_json_binary(&PausedResponse { paused: PAUSED.load(deps.storage)? }),
+        QueryMsg::SubmissionsBySubmitter { addr, limit, start_after } => to_json_binary(&get_submissions_by_submitter(deps, addr, limit, start_after)?),
+        QueryMsg::SubmissionsByRecipient { addr, limit, start_after } => to_json_binary(&get_submissions_by_recipient(deps, addr, limit, start_after)?),
+        QueryMsg::HasVoted { submission_id, addr } => to_json_binary(&has_voted(deps, submission_id, addr)?),
+        QueryMsg::VotingPower { addr } => to_json_binary(&get_voting_power(deps, env, addr)
+            .map_err(|err| StdError::generic_err(err.to_string()))?),
+        QueryMsg::PassingThreshold {  } => to_json_binary(&get_passing_threshold(deps, &CONFIG.load(deps.storage)?)?),
+        QueryMsg::SubmissionStatus { id } => to_json_binary(&get_submission_status(deps, env, id)?),
+        QueryMsg::AuctionState {  } => to_json_binary(&AUCTION_STATE.may_load(deps.storage)?.unwrap_or(AuctionState::Idle)),
+        QueryMsg::MintReserve {  } => to_json_binary(&get_mint_reserve(deps)?),
+        QueryMsg::ArtistProfile { artist } => to_json_binary(&get_artist_profile(deps, artist)?),
+        QueryMsg::ArtistLeaderboard { limit, start_after } => to_json_binary(&get_artist_leaderboard(deps, limit, start_after)?),
+        QueryMsg::CuratorStats { addr } => to_json_binary(&get_curator_stats(deps, addr)?),
+        QueryMsg::Delegation { addr } => to_json_binary(&get_delegation(deps, addr)?),
+        QueryMsg::FreeVoters { limit, start_after } => to_json_binary(&get_free_voters(deps, limit, start_after)?),
+        QueryMsg::Allowlisted { addr } => to_json_binary(&ALLOWLIST.has(deps.storage, deps.api.addr_validate(&addr)?)),
+        QueryMsg::EstimatedStartTime { queue_position } => to_json_binary(&get_estimated_start_time(deps, env, queue_position)?),
     }
 }
 
+fn get_live_nft_auction(
+    deps: Deps,
+    env: Env,
+) -> StdResult<LiveNftAuctionResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let auction = NFT_AUCTION.may_load(deps.storage)?;
+    let conclusion_paused = PAUSED.load(deps.storage)?.contains(&PauseScope::Conclusion);
+    let bid_gate = auction.clone().and_then(|auction| auction.bid_gate).or(config.clone().bid_gate);
+
+    let (time_remaining, minimum_next_bid, can_conclude) = match auction.clone() {
+        Some(auction) => {
+            //Bids must beat the highest bid by the minimum outbid
+            let minimum_next_bid = match auction.bid_count {
+                0 => Uint128::new(auction.reserve_price).max(Uint128::one()),
+                _ => get_minimum_outbid(&config, Uint128::new(auction.highest_bid.amount)),
+            };
+            (
+                auction.auction_end_time.saturating_sub(env.block.time.seconds()),
+                minimum_next_bid,
+                env.block.time.seconds() >= auction.auction_end_time && !conclusion_paused,
+            )
+        },
+        None => (0, Uint128::zero(), false),
+    };
+
+    Ok(LiveNftAuctionResponse {
+        auction,
+        time_remaining,
+        minimum_next_bid,
+        can_conclude,
+        next_pending: PENDING_AUCTION.load(deps.storage)?.into_iter().next(),
+        bid_gate,
+        proxy_bid: PROXY_BID.may_load(deps.storage)?,
+    })
+}
+
+fn get_live_bid_asset_auction(
+    deps: Deps,
+    env: Env,
+) -> StdResult<BidAssetAuctionResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let auction = ASSET_AUCTION.may_load(deps.storage)?;
+    let conclusion_paused = PAUSED.load(deps.storage)?.contains(&PauseScope::Conclusion);
+
+    let (time_remaining, minimum_next_bid, can_conclude) = match auction.clone() {
+        Some(auction) => (
+            auction.auction_end_time.saturating_sub(env.block.time.seconds()),
+            //Bids must be at least the highest bid plus the minimum outbid & more than 0
+            (Uint128::new(auction.highest_bid.amount) * (Decimal::one() + config.minimum_outbid)).max(Uint128::one()),
+            env.block.time.seconds() >= auction.auction_end_time && !conclusion_paused,
+        ),
+        None => (0, Uint128::zero(), false),
+    };
+
+    Ok(BidAssetAuctionResponse {
+        auction,
+        time_remaining,
+        minimum_next_bid,
+        can_conclude,
+        pending_donations: TREASURY.load(deps.storage)?.donations,
+    })
+}
+
+fn get_treasury(
+    deps: Deps,
+) -> StdResult<TreasuryResponse> {
+    let treasury = TREASURY.load(deps.storage)?;
+    let asset_auction = ASSET_AUCTION.may_load(deps.storage)?;
+
+    Ok(
+        TreasuryResponse {
+            escrowed_bids: treasury.escrowed_bids,
+            submission_fees: treasury.submission_fees,
+            escrowed_submission_fees: treasury.escrowed_submission_fees,
+            incentive_share: treasury.incentive_share,
+            donations: treasury.donations,
+            auctioned_asset: asset_auction.clone().map(|auction| auction.auctioned_asset).unwrap_or_default(),
+            escrowed_asset_bid: asset_auction.map(|auction| Uint128::new(auction.highest_bid.amount)).unwrap_or_default(),
+        }
+    )
+}
+
+fn get_burned(
+    deps: Deps,
+) -> StdResult<BurnedResponse> {
+    let burned: Vec<Asset> = BURNED
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, v)| v))
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    Ok(
+        BurnedResponse {
+            burned,
+        }
+    )
+}
+
 fn get_submissions(
     deps: Deps,
     submission_id: Option<u64>,
@@ -842,13 +2962,13 @@ fn get_submissions(
 ) -> StdResult<SubmissionsResponse> {
     let submissions: Vec<SubmissionItem> = match submission_id {
         Some(submission_id) => {
-            let submission = SUBMISSIONS.load(deps.storage, submission_id)?;
+            let submission = submissions().load(deps.storage, submission_id)?;
             vec![submission]
         },
         None => {
             let start = start_after.map(|index| Bound::ExclusiveRaw(index.to_be_bytes().to_vec()));
             
-            let submissions: StdResult<Vec<SubmissionItem>> = SUBMISSIONS
+            let submissions: StdResult<Vec<SubmissionItem>> = submissions()
                 .range(deps.storage, start, None, Order::Ascending)
                 .map(|item| item.map(|(_, v)| v))
                 .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
@@ -864,6 +2984,134 @@ fn get_submissions(
     )
 }
 
+fn get_submissions_by_submitter(
+    deps: Deps,
+    addr: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<SubmissionsResponse> {
+    let submitter = deps.api.addr_validate(&addr)?;
+    let start = start_after.map(Bound::exclusive);
+
+    let submissions: StdResult<Vec<SubmissionItem>> = submissions()
+        .idx.submitter
+        .prefix(submitter)
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, v)| v))
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .collect();
+
+    Ok(
+        SubmissionsResponse {
+            submissions: submissions?,
+        }
+    )
+}
+
+fn get_submissions_by_recipient(
+    deps: Deps,
+    addr: String,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<SubmissionsResponse> {
+    let proceed_recipient = deps.api.addr_validate(&addr)?;
+    let start = start_after.map(Bound::exclusive);
+
+    let submissions: StdResult<Vec<SubmissionItem>> = submissions()
+        .idx.proceed_recipient
+        .prefix(proceed_recipient)
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, v)| v))
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .collect();
+
+    Ok(
+        SubmissionsResponse {
+            submissions: submissions?,
+        }
+    )
+}
+
+fn has_voted(
+    deps: Deps,
+    submission_id: u64,
+    addr: String,
+) -> StdResult<bool> {
+    let voter = deps.api.addr_validate(&addr)?;
+
+    //Submissions being voted on
+    if let Some(submission) = submissions().may_load(deps.storage, submission_id)? {
+        return Ok(submission.curators.contains(&voter) || submission.delegated_voters.contains(&voter));
+    }
+    //Submissions that passed
+    let live_auction = NFT_AUCTION.may_load(deps.storage)?;
+    let auction = PENDING_AUCTION.load(deps.storage)?
+        .into_iter()
+        .chain(live_auction)
+        .find(|auction| auction.submission_id == Some(submission_id));
+
+    Ok(auction.map(|auction| auction.submission_info.curators.contains(&voter) || auction.submission_info.delegated_voters.contains(&voter)).unwrap_or(false))
+}
+
+fn get_submission_status(
+    deps: Deps,
+    env: Env,
+    submission_id: u64,
+) -> StdResult<SubmissionStatus> {
+    if let Some(submission) = submissions().may_load(deps.storage, submission_id)? {
+        //Expired submissions are removed on the next vote
+        if env.block.time.seconds() > submission.submission_end_time {
+            return Ok(SubmissionStatus::Expired);
+        }
+        return Ok(SubmissionStatus::Voting);
+    }
+    if let Some(auction) = NFT_AUCTION.may_load(deps.storage)? {
+        if auction.submission_id == Some(submission_id) {
+            return Ok(SubmissionStatus::Live);
+        }
+    }
+    if let Some(position) = PENDING_AUCTION.load(deps.storage)?
+        .iter()
+        .position(|auction| auction.submission_id == Some(submission_id)) {
+        return Ok(SubmissionStatus::Queued { position: position as u64 });
+    }
+
+    SUBMISSION_OUTCOMES.load(deps.storage, submission_id)
+}
+
+/// Assumes each auction sells in its first period, auctions without bids are extended
+fn get_estimated_start_time(
+    deps: Deps,
+    env: Env,
+    queue_position: u64,
+) -> StdResult<u64> {
+    let config = CONFIG.load(deps.storage)?;
+
+    //The next auction starts when the live auction ends
+    let next_start_time = match NFT_AUCTION.may_load(deps.storage)? {
+        Some(auction) => auction.auction_end_time.max(env.block.time.seconds()),
+        None => env.block.time.seconds(),
+    };
+
+    Ok(next_start_time + (queue_position * SECONDS_PER_DAY * config.auction_period))
+}
+
+fn get_bid_history(
+    deps: Deps,
+    auction_id: u64,
+    limit: Option<u32>,
+    start_after: Option<u64>,
+) -> StdResult<BidHistoryResponse> {
+    let bids = BIDS
+        .prefix(auction_id)
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| item.map(|(_, bid)| bid))
+        .collect::<StdResult<Vec<Bid>>>()?;
+
+    Ok(BidHistoryResponse { bids })
+}
+
 fn get_pending_auctions(
     deps: Deps,
     limit: Option<u32>,
@@ -890,6 +3138,156 @@ fn get_pending_auctions(
 }
 
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version = get_contract_version(deps.storage)?;
+    if version.contract != CONTRACT_NAME {
+        return Err(ContractError::CustomError { val: format!("Can't migrate from contract {}", version.contract) });
+    }
+
+    let mut response = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", version.version.clone());
+    if version.version == legacy::CONTRACT_VERSION {
+        response = response.add_attribute("reindexed_submissions", migrate_legacy_state(deps.branch(), &env)?.to_string());
+    } else if version.version != CONTRACT_VERSION {
+        return Err(ContractError::CustomError { val: format!("Can't migrate from version {}", version.version) });
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(response)
+}
+
+/// Convert v0.1.0 storage & initialize the items it didn't have, returns the reindexed submissions
+fn migrate_legacy_state(
+    deps: DepsMut,
+    env: &Env,
+) -> Result<usize, ContractError> {
+    //New settings start at their instantiate defaults
+    let legacy_config = legacy::CONFIG.load(deps.storage)?;
+    FREE_VOTERS.save(deps.storage, legacy_config.free_vote_addr.clone(), &FreeVoter { weight: 1, expiration: None })?;
+    let config = Config {
+        owner: legacy_config.owner,
+        bid_denom: AssetInfo::NativeToken { denom: legacy_config.bid_denom },
+        minimum_outbid: legacy_config.minimum_outbid,
+        incentive_denom: legacy_config.incentive_denom.map(|denom| AssetInfo::NativeToken { denom }),
+        incentive_bid_percent: legacy_config.incentive_bid_percent,
+        current_submission_id: legacy_config.current_submission_id,
+        sg721_addr: legacy_config.sg721_addr,
+        minter_addr: legacy_config.minter_addr,
+        mint_cost: legacy_config.mint_cost,
+        submission_cost: legacy_config.submission_cost,
+        submission_limit: legacy_config.submission_limit,
+        submission_total: legacy_config.submission_total,
+        submission_vote_period: legacy_config.submission_vote_period,
+        curation_threshold: legacy_config.curation_threshold,
+        auction_period: legacy_config.auction_period,
+        asset_auction_period: AUCTION_PERIOD,
+        soft_close_period: SOFT_CLOSE_PERIOD,
+        royalty_policy: RoyaltyPolicy::BidAssetAuction,
+        royalty_denom: String::from("ustars"),
+        burn_strategy: BurnStrategy::Burn,
+        guardian: None,
+        no_bid_policy: NoBidPolicy::Rotate { max_extensions: NO_BID_EXTENSIONS },
+        reserve_price: 0u128,
+        bid_gate: None,
+        mint_fee_source: MintFeeSource::Reserve,
+        low_reserve_mints: LOW_RESERVE_MINTS,
+        artist_discount: None,
+        curator_reward_percent: Decimal::zero(),
+        curator_hit_price: 0u128,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    //The live NFT auction's bids move to the bid history
+    let live_auction = match legacy::NFT_AUCTION.may_load(deps.storage)? {
+        Some(auction) => {
+            let auction_id = get_next_auction_id(deps.storage)?;
+            for (seq, bid) in auction.bids.iter().enumerate() {
+                BIDS.save(deps.storage, (auction_id, seq as u64), bid)?;
+            }
+            let live_auction = Auction {
+                submission_id: None,
+                submission_info: auction.submission_info.into(),
+                auction_id,
+                bid_count: auction.bids.len() as u64,
+                highest_bid: auction.highest_bid,
+                auction_end_time: auction.auction_end_time,
+                reserve_price: 0,
+                extensions: 0,
+                bid_gate: None,
+            };
+            NFT_AUCTION.save(deps.storage, &live_auction)?;
+            Some(live_auction)
+        },
+        None => None,
+    };
+    let pending_auctions: Vec<Auction> = legacy::PENDING_AUCTION.may_load(deps.storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|auction| Auction {
+            submission_id: None,
+            submission_info: auction.submission_info.into(),
+            auction_id: 0, //will set when active
+            bid_count: 0,
+            highest_bid: auction.highest_bid,
+            auction_end_time: 0, //will set when active
+            reserve_price: 0, //will set when active
+            extensions: 0,
+            bid_gate: None,
+        })
+        .collect();
+    PENDING_AUCTION.save(deps.storage, &pending_auctions)?;
+
+    //The bid asset auction used to conclude with the NFT auction
+    if let Some(auction) = legacy::ASSET_AUCTION.may_load(deps.storage)? {
+        let mut auctioned_asset = vec![];
+        if !auction.auctioned_asset.amount.is_zero() {
+            auctioned_asset.push(Asset {
+                info: AssetInfo::NativeToken { denom: auction.auctioned_asset.denom },
+                amount: auction.auctioned_asset.amount,
+            });
+        }
+        ASSET_AUCTION.save(deps.storage, &BidAssetAuction {
+            auctioned_asset,
+            highest_bid: auction.highest_bid,
+            auction_end_time: live_auction.as_ref().map_or(env.block.time.seconds(), |auction| auction.auction_end_time),
+        })?;
+    }
+
+    //Only the highest NFT bid was escrowed
+    TREASURY.save(deps.storage, &Treasury {
+        escrowed_bids: Uint128::new(live_auction.as_ref().map_or(0, |auction| auction.highest_bid.amount)),
+        ..Treasury::default()
+    })?;
+    //The rest of the bid_denom balance is submission costs since the last conclusion.
+    //Mint fees also came out of the ustars balance, so a ustars bid_denom's is left to the mint reserve.
+    if config.bid_denom != (AssetInfo::NativeToken { denom: String::from("ustars") }) {
+        let balance = deps.querier.query_balance(env.contract.address.to_string(), config.bid_denom.to_string())?.amount;
+        let mut treasury = TREASURY.load(deps.storage)?;
+        treasury.submission_fees = balance.saturating_sub(get_reserved_balance(deps.storage, &config, &config.bid_denom)?);
+        TREASURY.save(deps.storage, &treasury)?;
+    }
+    //Mint fees were paid from the contract's ustars balance before the reserve was tracked
+    let balance = deps.querier.query_balance(env.contract.address.to_string(), "ustars")?.amount;
+    let reserved = get_reserved_balance(deps.storage, &config, &AssetInfo::NativeToken { denom: String::from("ustars") })?;
+    MINT_RESERVE.save(deps.storage, &balance.saturating_sub(reserved))?;
+
+    PAUSED.save(deps.storage, &vec![])?;
+    AUCTION_STATE.save(deps.storage, &if live_auction.is_some() { AuctionState::Live } else { AuctionState::Idle })?;
+    CURATOR_REWARDS.save(deps.storage, &CuratorRewards::default())?;
+
+    //Submissions are re-saved to add their index keys
+    let legacy_submissions = legacy::SUBMISSIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, legacy::SubmissionItem)>>>()?;
+    for (submission_id, submission_info) in legacy_submissions.iter() {
+        submissions().save(deps.storage, *submission_id, &submission_info.clone().into())?;
+    }
+
+    Ok(legacy_submissions.len())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     match msg.id {
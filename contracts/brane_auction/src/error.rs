@@ -19,6 +19,9 @@ pub enum ContractError {
 
     #[error("InvalidTokenURI: {uri}")]
     InvalidTokenURI { uri: String },
+
+    #[error("Paused: {scope}")]
+    Paused { scope: String },
 }
 
 impl From<OverflowError> for ContractError {
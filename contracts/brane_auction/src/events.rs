@@ -0,0 +1,188 @@
+//! Events for indexers, the chain prefixes each type with `wasm-`.
+//! Addresses, ids, amounts, booleans & timestamps (in seconds) are plain strings.
+//! Structs, enums & lists are JSON using the same schema as the queries, except auction states which are snake_case names.
+use cosmwasm_std::{to_json_string, Addr, Event, StdResult, Uint128};
+
+use crate::msgs::{Asset, AssetInfo, AuctionState, Config};
+use crate::state::SubmissionItem;
+
+pub const SUBMISSION_EVENT: &str = "brane_submission";
+pub const VOTE_EVENT: &str = "brane_vote";
+pub const BID_EVENT: &str = "brane_bid";
+pub const AUCTION_SETTLED_EVENT: &str = "brane_auction_settled";
+pub const ASSET_AUCTION_SETTLED_EVENT: &str = "brane_asset_auction_settled";
+pub const CONFIG_UPDATED_EVENT: &str = "brane_config_updated";
+pub const AUCTION_STATE_EVENT: &str = "brane_auction_state";
+pub const NO_BID_EVENT: &str = "brane_no_bid";
+pub const MINT_RESERVE_LOW_EVENT: &str = "brane_mint_reserve_low";
+
+/// `brane_submission`
+/// - `submission_id`
+/// - `submitter`
+/// - `payment`: submission cost paid in the bid denom
+/// - `submission`: JSON `SubmissionItem`
+pub fn submission_event(
+    submission_id: u64,
+    payment: Uint128,
+    submission: &SubmissionItem,
+) -> StdResult<Event> {
+    Ok(Event::new(SUBMISSION_EVENT)
+        .add_attribute("submission_id", submission_id.to_string())
+        .add_attribute("submitter", submission.submission.submitter.to_string())
+        .add_attribute("payment", payment)
+        .add_attribute("submission", to_json_string(submission)?))
+}
+
+/// `brane_vote`
+/// - `curator`
+/// - `votes`: votes added to each submission
+/// - `submission_ids`: JSON list of submissions voted on
+/// - `passed`: JSON list of submissions that were added to the auction queue
+pub fn vote_event(
+    curator: &Addr,
+    votes: u64,
+    submission_ids: &[u64],
+    passed: &[u64],
+) -> StdResult<Event> {
+    Ok(Event::new(VOTE_EVENT)
+        .add_attribute("curator", curator.to_string())
+        .add_attribute("votes", votes.to_string())
+        .add_attribute("submission_ids", to_json_string(submission_ids)?)
+        .add_attribute("passed", to_json_string(passed)?))
+}
+
+/// `brane_bid`
+/// - `auction`: `nft` or `asset`
+/// - `bidder`
+/// - `amount`
+/// - `asset`: JSON `AssetInfo` of the bid
+/// - `auction_end_time`
+pub fn bid_event(
+    auction: &str,
+    bidder: &Addr,
+    amount: u128,
+    asset: &AssetInfo,
+    auction_end_time: u64,
+) -> StdResult<Event> {
+    Ok(Event::new(BID_EVENT)
+        .add_attribute("auction", auction)
+        .add_attribute("bidder", bidder.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("asset", to_json_string(asset)?)
+        .add_attribute("auction_end_time", auction_end_time.to_string()))
+}
+
+/// `brane_auction_settled`
+/// - `token_uri`
+/// - `proceed_recipient`
+/// - `winner`
+/// - `winning_bid`
+/// - `proceeds`: sent to the proceed_recipient
+/// - `incentive_share`: added to the next bid asset auction
+/// - `auction_end_time`
+pub fn auction_settled_event(
+    token_uri: &str,
+    proceed_recipient: &Addr,
+    winner: &Addr,
+    winning_bid: u128,
+    proceeds: Uint128,
+    incentive_share: Uint128,
+    auction_end_time: u64,
+) -> Event {
+    Event::new(AUCTION_SETTLED_EVENT)
+        .add_attribute("token_uri", token_uri)
+        .add_attribute("proceed_recipient", proceed_recipient.to_string())
+        .add_attribute("winner", winner.to_string())
+        .add_attribute("winning_bid", winning_bid.to_string())
+        .add_attribute("proceeds", proceeds)
+        .add_attribute("incentive_share", incentive_share)
+        .add_attribute("auction_end_time", auction_end_time.to_string())
+}
+
+/// `brane_no_bid`
+/// - `token_uri`
+/// - `action`: `extend`, `rotate` or `drop`
+/// - `extensions`: times the auction was extended
+/// - `reserve_price`: reserve price of the extended auction
+/// - `refund`: submission fee refunded to the submitter if dropped
+/// - `auction_end_time`: the extended end time, 0 if rotated or dropped
+pub fn no_bid_event(
+    token_uri: &str,
+    action: &str,
+    extensions: u64,
+    reserve_price: u128,
+    refund: Uint128,
+    auction_end_time: u64,
+) -> Event {
+    Event::new(NO_BID_EVENT)
+        .add_attribute("token_uri", token_uri)
+        .add_attribute("action", action)
+        .add_attribute("extensions", extensions.to_string())
+        .add_attribute("reserve_price", reserve_price.to_string())
+        .add_attribute("refund", refund)
+        .add_attribute("auction_end_time", auction_end_time.to_string())
+}
+
+/// `brane_asset_auction_settled`
+/// - `winner`: omitted if there was no auction or no bids
+/// - `winning_bid`
+/// - `sold`: JSON list of `Asset`s sent to the winner
+/// - `burned`: JSON `Asset` or null
+/// - `curator_rewards`: part of the winning bid shared by curator score
+/// - `next_auction`: JSON list of `Asset`s in the new auction
+/// - `next_auction_end_time`: 0 if no auction was started
+pub fn asset_auction_settled_event(
+    winner: Option<&Addr>,
+    winning_bid: u128,
+    sold: &[Asset],
+    burned: Option<&Asset>,
+    curator_rewards: Uint128,
+    next_auction: &[Asset],
+    next_auction_end_time: u64,
+) -> StdResult<Event> {
+    let mut event = Event::new(ASSET_AUCTION_SETTLED_EVENT);
+    //Empty attribute values are rejected
+    if let Some(winner) = winner {
+        event = event.add_attribute("winner", winner.to_string());
+    }
+    Ok(event
+        .add_attribute("winning_bid", winning_bid.to_string())
+        .add_attribute("sold", to_json_string(sold)?)
+        .add_attribute("burned", to_json_string(&burned)?)
+        .add_attribute("curator_rewards", curator_rewards)
+        .add_attribute("next_auction", to_json_string(next_auction)?)
+        .add_attribute("next_auction_end_time", next_auction_end_time.to_string()))
+}
+
+/// `brane_config_updated`
+/// - `config`: JSON `Config`
+pub fn config_updated_event(
+    config: &Config,
+) -> StdResult<Event> {
+    Ok(Event::new(CONFIG_UPDATED_EVENT)
+        .add_attribute("config", to_json_string(config)?))
+}
+
+/// `brane_auction_state`
+/// - `from`: previous state
+/// - `to`: new state
+pub fn auction_state_event(
+    from: &AuctionState,
+    to: &AuctionState,
+) -> Event {
+    Event::new(AUCTION_STATE_EVENT)
+        .add_attribute("from", from.to_string())
+        .add_attribute("to", to.to_string())
+}
+
+/// `brane_mint_reserve_low`, emitted when a mint leaves the reserve below the config's low_reserve_mints
+/// - `reserve`: ustars left for mint fees
+/// - `mints_remaining`
+pub fn mint_reserve_low_event(
+    reserve: Uint128,
+    mints_remaining: u64,
+) -> Event {
+    Event::new(MINT_RESERVE_LOW_EVENT)
+        .add_attribute("reserve", reserve)
+        .add_attribute("mints_remaining", mints_remaining.to_string())
+}
@@ -4,6 +4,7 @@
 #![allow(non_camel_case_types)]
 pub mod contracts;
 pub mod error;
+pub mod events;
 pub mod state;
 pub mod msgs;
 pub mod reply;
@@ -1,7 +1,10 @@
-use cosmwasm_std::{Addr, Decimal, Timestamp};
+use std::fmt;
+
+use cosmwasm_std::{Addr, Coin, Decimal, Empty, Timestamp, Uint128};
 use cosmwasm_schema::cw_serde;
+use cw20::Cw20ReceiveMsg;
 
-use crate::state::{Auction, SubmissionInfo, SubmissionItem};
+use crate::state::{ArtistProfile, Auction, Bid, CuratorStats, BidAssetAuction, Donation, FreeVoter, ProxyBid, SubmissionInfo, SubmissionItem, SubmissionMetadata};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -10,47 +13,61 @@ pub struct InstantiateMsg {
     pub sg721_code_id: Option<u64>,
     /// making this an option makes testing easier & allows pre-existing collections to be added if they give the contract mint ability
     pub collection_params: Option<CollectionParams>, 
+    /// Collection start trading time, None lets the NFTs trade as soon as they're minted
+    pub start_trading_time: Option<Timestamp>,
     /// Minter address
     /// If you have an existing collection, pass the base-minter && sg721 here to skip the instantiation
+    /// The contract must be the sg721's creator to mint
     pub minter_addr: Option<String>,     
     pub sg721_addr: Option<String>, 
     ///////
     pub base_factory_address: String, //testnet: stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx, mainnet: stars1klnzgwfvca8dnjeasx00v00f49l6nplnvnsxyc080ph2h8qxe4wss4d3ga
     /// Bid denom
-    pub bid_denom: String,
+    pub bid_denom: AssetInfo,
     /// Memecoin denom
-    pub incentive_denom: Option<String>,
+    pub incentive_denom: Option<AssetInfo>,
     /// First submission for the first NFT auction of the collection
     pub first_submission: SubmissionInfo,
     ///Mint cost
     /// testnet: 50_000_000u64
     /// mainnet: 5_000_000u64
     pub mint_cost: u64,
-    /// Free vote address
+    /// Initial free voter, votes & submits for free with 1 vote
     pub free_vote_addr: String,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     SubmitNft { 
-        proceed_recipient: String,
+        /// None uses the artist profile's payout_address, or the submitter
+        proceed_recipient: Option<String>,
+        /// Minted as is without metadata, so the metadata JSON URI.
+        /// With metadata it's the image & can't be a metadata JSON.
         token_uri: String,
+        /// Minted as a metadata JSON data URI with the token_uri as its image
+        metadata: Option<SubmissionMetadata>,
     },
     /// Submissions have 7 days to get votes, after 7 days any votes will delete the submission
     VoteToCurate { submission_ids: Vec<u64> },
-    BidForNft { },
+    /// Proxy bids send max_bid & are countered up to it by minimum_outbid, unused escrow is refunded
+    BidForNft { max_bid: Option<Uint128> },
     BidForAssets { },
+    /// Cw20 entry point for submissions & bids, msg is a Cw20HookMsg
+    Receive(Cw20ReceiveMsg),
     /// Transfer NFT to highest bidder & handle incentive distributions
     ConcludeAuction { },
+    /// Settle the bid asset auction once it ends & start the next one
+    ConcludeBidAssetAuction { },
+    /// Add the sent assets to the next bid asset auction
+    Donate { },
     ////These are all controlled by the owner who will be a DAODAO NFT staking contract
     // MigrateMinter { new_code_id: u64 },
     // MigrateContract { new_code_id: u64 },
     UpdateConfig {
         owner: Option<String>,
-        free_vote_addr: Option<String>,
-        bid_denom: Option<String>,
+        bid_denom: Option<AssetInfo>,
         minimum_outbid: Option<Decimal>,
-        incentive_denom: Option<String>,
+        incentive_denom: Option<AssetInfo>,
         // incentive_distribution_amount: Option<u128>,
         incentive_bid_percent: Option<Decimal>,
         mint_cost: Option<u128>,
@@ -59,15 +76,136 @@ pub enum ExecuteMsg {
         submission_vote_period: Option<u64>,
         curation_threshold: Option<Decimal>,
         auction_period: Option<u64>,
+        asset_auction_period: Option<u64>,
+        soft_close_period: Option<u64>,
+        royalty_policy: Option<RoyaltyPolicy>,
+        royalty_denom: Option<String>,
+        burn_strategy: Option<BurnStrategy>,
+        guardian: Option<String>,
+        no_bid_policy: Option<NoBidPolicy>,
+        reserve_price: Option<u128>,
+        mint_fee_source: Option<MintFeeSource>,
+        low_reserve_mints: Option<u64>,
+        curator_reward_percent: Option<Decimal>,
+        curator_hit_price: Option<u128>,
+    },
+    /// Pause scopes, owner or guardian only
+    Pause {
+        scopes: Vec<PauseScope>,
+    },
+    /// Unpause scopes, owner or guardian only. None unpauses everything.
+    Unpause {
+        scopes: Option<Vec<PauseScope>>,
+    },
+    /// Advance whatever is due: settle ended auctions, start the next auction & prune expired submissions
+    Crank { },
+    /// Cancel a live NFT auction that can't be concluded, owner or guardian only.
+    /// Available a grace period after the auction ends.
+    /// Refunds the highest bidder & requeues or drops the submission.
+    EmergencyCancelAuction {
+        /// Add the submission to the end of the pending auctions
+        requeue: bool,
+        reason: String,
     },
+    /// Update the collection's royalty info on the sg721, None removes royalties
+    UpdateRoyaltyInfo {
+        royalty_info: Option<RoyaltyInfoResponse>,
+    },
+    /// Update the collection's start trading time through the minter, owner only
+    UpdateStartTradingTime {
+        start_trading_time: Option<Timestamp>,
+    },
+    /// Update the collection's info on the sg721, owner only
+    UpdateCollectionInfo {
+        description: Option<String>,
+        image: Option<String>,
+        external_link: Option<Option<String>>,
+        explicit_content: Option<bool>,
+    },
+    /// Freeze the collection's info on the sg721, owner only. This can't be undone.
+    FreezeCollectionInfo { },
     //////
+    /// Route royalties held by the contract according to the royalty policy
+    DistributeRoyalties { },
+    /// Set the bid gate for NFT auctions, owner only. None removes it.
+    /// The live auction's gate overrides the config's.
+    SetBidGate {
+        gate: Option<BidGate>,
+        live_auction_only: bool,
+    },
+    /// Add & remove bidders on the allowlist, owner only
+    UpdateAllowlist {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Add the ustars sent to the mint fee reserve
+    FundMintReserve { },
+    /// Add or replace a free voter, owner only. Free voters vote with their weight & skip the submission cost.
+    AddFreeVoter {
+        addr: String,
+        weight: u64,
+        /// Block time in seconds, None never expires
+        expiration: Option<u64>,
+    },
+    /// Remove a free voter, owner only
+    RemoveFreeVoter { addr: String },
+    /// Set the sender's artist profile, None leaves a field as is & an empty string clears it
+    UpdateArtistProfile {
+        display_name: Option<String>,
+        payout_address: Option<String>,
+    },
+    /// Set the discount for artists with a track record, owner only. None removes it.
+    SetArtistDiscount {
+        discount: Option<ArtistDiscount>,
+    },
+    /// Send the sender's curator rewards in the incentive denom
+    ClaimCuratorRewards { },
+    /// Add the sender's NFT votes to the delegate's when it curates, replaces any current delegation
+    DelegateVotes { to: String },
+    /// Remove the sender's delegation
+    Undelegate { },
+}
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    SubmitNft { 
+        /// None uses the artist profile's payout_address, or the submitter
+        proceed_recipient: Option<String>,
+        token_uri: String,
+        metadata: Option<SubmissionMetadata>,
+    },
+    BidForNft { max_bid: Option<Uint128> },
+    BidForAssets { },
+    Donate { },
 }
+
 #[cw_serde]
 pub enum BaseMinterExecuteMsg {
     Mint { token_uri: String },
     UpdateStartTradingTime(Option<Timestamp>),
 }
 
+#[cw_serde]
+pub enum BaseMinterQueryMsg {
+    Config {},
+}
+
+#[cw_serde]
+pub struct BaseMinterConfig {
+    pub factory: Addr,
+    pub collection_code_id: u64,
+    pub mint_price: Coin,
+    pub extension: Empty,
+}
+
+#[cw_serde]
+pub struct BaseMinterConfigResponse {
+    pub collection_address: String,
+    pub config: BaseMinterConfig,
+}
+
 #[cw_serde]
 pub enum QueryMsg {
     /// Return contract config
@@ -83,24 +221,81 @@ pub enum QueryMsg {
         limit: Option<u32>,
         start_after: Option<u64>
     },
+    /// Return an NFT auction's bids, oldest first
+    BidHistory {
+        auction_id: u64,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
     /// Return live auction info
     LiveNftAuction {},
     /// Return bid asset auction info
     LiveBidAssetAuction {},
+    /// Return total burned per asset
+    Burned {},
+    /// Return the bid denom ledgers & live bid asset auction holdings
+    Treasury {},
+    /// Return paused scopes
+    Paused {},
+    /// Return a submitter's submissions that are still being voted on
+    SubmissionsBySubmitter {
+        addr: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+    /// Return submissions still being voted on that pay out to a proceed recipient
+    SubmissionsByRecipient {
+        addr: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+    /// Return whether an address voted for a submission, directly or through its delegate
+    HasVoted {
+        submission_id: u64,
+        addr: String,
+    },
+    /// Return an address's votes per submission, its own & delegated
+    VotingPower { addr: String },
+    /// Return the votes a submission needs to be auctioned
+    PassingThreshold {},
+    /// Return where a submission is in its lifecycle
+    SubmissionStatus { id: u64 },
+    /// Return the estimated start time of the pending auction at a queue position (0 is next)
+    EstimatedStartTime { queue_position: u64 },
+    /// Return the NFT auction state
+    AuctionState {},
+    /// Return if the address is on the bid allowlist
+    Allowlisted { addr: String },
+    /// Return the mint fee reserve
+    MintReserve {},
+    /// Return an artist's profile
+    ArtistProfile { artist: String },
+    /// Return artist profiles by total proceeds, highest first
+    ArtistLeaderboard {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+    /// Return a curator's score & rewards
+    CuratorStats { addr: String },
+    /// Return an address's delegate & delegators
+    Delegation { addr: String },
+    /// Return free voters
+    FreeVoters {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
 }
 
 #[cw_serde]
 pub struct Config {
     /// Contract owner
     pub owner: Addr,
-    /// Address that gets a free vote
-    pub free_vote_addr: Addr,
     /// Bid denom
-    pub bid_denom: String,
+    pub bid_denom: AssetInfo,
     /// Minimum percent to increase bid by
     pub minimum_outbid: Decimal,
     /// Memecoin denom
-    pub incentive_denom: Option<String>,
+    pub incentive_denom: Option<AssetInfo>,
     // Memecoin distribution amount
     // pub incentive_distribution_amount: u128,
     /// Percent of Bid to distribute to incentive holders
@@ -127,6 +322,194 @@ pub struct Config {
     pub curation_threshold: Decimal,
     /// Auction period (in days)
     pub auction_period: u64, 
+    /// Bid asset auction period (in days)
+    pub asset_auction_period: u64,
+    /// Bid asset auction bids within this window of the end extend it to this window (in seconds)
+    pub soft_close_period: u64,
+    /// Where royalty income is routed
+    pub royalty_policy: RoyaltyPolicy,
+    /// Denom royalties are paid in
+    pub royalty_denom: String,
+    /// How winning bid asset auction bids are burned
+    pub burn_strategy: BurnStrategy,
+    /// Address that can pause & unpause alongside the owner
+    pub guardian: Option<Addr>,
+    /// What happens to NFT auctions that end without bids
+    pub no_bid_policy: NoBidPolicy,
+    /// Minimum first bid for new NFT auctions in the bid_denom
+    pub reserve_price: u128,
+    /// Who can bid on NFT auctions without their own gate
+    pub bid_gate: Option<BidGate>,
+    /// Where the ustars mint fee comes from
+    pub mint_fee_source: MintFeeSource,
+    /// Conclusions warn when the mint reserve covers fewer mints than this
+    pub low_reserve_mints: u64,
+    /// Discount for artists with a track record
+    pub artist_discount: Option<ArtistDiscount>,
+    /// Percent of winning bid asset auction bids shared by curator score instead of burned, none by default
+    pub curator_reward_percent: Decimal,
+    /// Votes on pieces that sell above this price in the bid_denom raise curator scores, others lower them
+    pub curator_hit_price: u128,
+}
+
+/// Discount for artists that sold at least min_sold pieces
+#[cw_serde]
+pub struct ArtistDiscount {
+    pub min_sold: u64,
+    /// Taken off the submission_cost
+    pub submission_cost_discount: Decimal,
+    /// Taken off the curation passing threshold
+    pub curation_threshold_discount: Decimal,
+}
+
+/// If the source can't cover the mint fee, conclusions fall back to the mint reserve & then to a ustars winning bid.
+/// If neither covers it, the conclusion errors & can be retried after FundMintReserve.
+#[cw_serde]
+pub enum MintFeeSource {
+    /// The mint reserve
+    Reserve,
+    /// The winning bid if the bid_denom is ustars & the bid covers it
+    WinningBid,
+}
+
+/// Who can bid on NFT auctions
+#[cw_serde]
+pub enum BidGate {
+    /// Must hold at least min_nfts of the collection
+    Holders { min_nfts: u64 },
+    /// Must be on the owner-managed allowlist
+    Allowlist,
+    /// Must hold at least min_balance of the incentive_denom
+    IncentiveBalance { min_balance: Uint128 },
+}
+
+/// NFT auctions that end without bids are extended by a day
+#[cw_serde]
+pub enum NoBidPolicy {
+    /// Extend up to max_extensions times, then move to the back of the pending auctions
+    Rotate { max_extensions: u64 },
+    /// Extend up to max_extensions times, then drop the submission & refund the submitter's fee
+    /// out of the submission fees that haven't been auctioned yet
+    Drop { max_extensions: u64 },
+    /// Extend indefinitely, lowering the reserve price by this ratio each time
+    LowerReserve { decrease: Decimal },
+}
+
+/// Circuit breakers, outbid refunds are sent with the new bid so there is nothing to withdraw
+#[cw_serde]
+pub enum PauseScope {
+    /// SubmitNft
+    Submissions,
+    /// VoteToCurate
+    Voting,
+    /// BidForNft
+    NftBids,
+    /// BidForAssets & Donate
+    AssetBids,
+    /// ConcludeAuction & ConcludeBidAssetAuction
+    Conclusion,
+}
+
+#[cw_serde]
+pub enum BurnStrategy {
+    /// BankMsg::Burn for native denoms, Burn for cw20s
+    Burn,
+    /// TokenFactory MsgBurn for the contract's factory denoms, BankMsg::Burn for other native denoms.
+    /// Cw20s use Burn.
+    TokenFactory,
+    /// Send to a sink address
+    Sink { addr: String },
+}
+
+#[cw_serde]
+pub enum AssetInfo {
+    /// Native or IBC denom
+    NativeToken { denom: String },
+    /// Cw20 contract
+    Token { address: Addr },
+}
+
+impl fmt::Display for AssetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssetInfo::NativeToken { denom } => write!(f, "{}", denom),
+            AssetInfo::Token { address } => write!(f, "{}", address),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum RoyaltyPolicy {
+    /// Add royalties to the live bid asset auction (royalty_denom must be the bid_denom)
+    BidAssetAuction,
+    /// Split royalties between past artists pro-rata to their sold pieces
+    Artists,
+    /// Send royalties to a treasury
+    Treasury { addr: String },
+}
+
+#[cw_serde]
+pub struct MintReserveResponse {
+    /// ustars held for mint fees
+    pub reserve: Uint128,
+    /// Mints the reserve covers at the current mint_cost
+    pub mints_remaining: u64,
+}
+
+#[cw_serde]
+pub struct BidHistoryResponse {
+    pub bids: Vec<Bid>,
+}
+
+#[cw_serde]
+pub struct FreeVoterResponse {
+    pub addr: Addr,
+    pub voter: FreeVoter,
+}
+
+#[cw_serde]
+pub struct FreeVotersResponse {
+    pub voters: Vec<FreeVoterResponse>,
+}
+
+#[cw_serde]
+pub struct DelegationResponse {
+    pub addr: Addr,
+    pub delegate: Option<Addr>,
+    pub delegators: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct VotingPowerResponse {
+    /// Free voter weight or NFTs held
+    pub votes: u64,
+    /// Votes of delegators that can vote
+    pub delegated_votes: u64,
+    /// Votes counted when curating
+    pub total: u64,
+}
+
+#[cw_serde]
+pub struct CuratorStatsResponse {
+    pub addr: Addr,
+    pub stats: CuratorStats,
+}
+
+#[cw_serde]
+pub struct ArtistProfileResponse {
+    pub artist: Addr,
+    pub profile: ArtistProfile,
+}
+
+#[cw_serde]
+pub struct ArtistProfilesResponse {
+    pub profiles: Vec<ArtistProfileResponse>,
 }
 
 #[cw_serde]
@@ -134,6 +517,102 @@ pub struct SubmissionsResponse {
     pub submissions: Vec<SubmissionItem>,
 }
 
+#[cw_serde]
+pub struct TreasuryResponse {
+    /// Escrowed bids on the live NFT auction
+    pub escrowed_bids: Uint128,
+    /// Submission costs for the next bid asset auction
+    pub submission_fees: Uint128,
+    /// Submission costs of unresolved submissions
+    pub escrowed_submission_fees: Uint128,
+    /// Incentive share of NFT sales for the next bid asset auction
+    pub incentive_share: Uint128,
+    /// Donations for the next bid asset auction
+    pub donations: Vec<Donation>,
+    /// Assets in the live bid asset auction
+    pub auctioned_asset: Vec<Asset>,
+    /// Escrowed incentive denom bid on the live bid asset auction
+    pub escrowed_asset_bid: Uint128,
+}
+
+#[cw_serde]
+pub struct BidAssetAuctionResponse {
+    /// None between auctions
+    pub auction: Option<BidAssetAuction>,
+    /// Seconds until the auction ends
+    pub time_remaining: u64,
+    /// Lowest bid that will be accepted
+    pub minimum_next_bid: Uint128,
+    /// The auction has ended & conclusion isn't paused
+    pub can_conclude: bool,
+    /// Donations that will be added to the next auction
+    pub pending_donations: Vec<Donation>,
+}
+
+#[cw_serde]
+pub struct LiveNftAuctionResponse {
+    /// None between auctions
+    pub auction: Option<Auction>,
+    /// Seconds until the auction ends
+    pub time_remaining: u64,
+    /// Lowest bid that will be accepted
+    pub minimum_next_bid: Uint128,
+    /// The auction has ended & conclusion isn't paused
+    pub can_conclude: bool,
+    /// Next pending auction
+    pub next_pending: Option<Auction>,
+    /// Gate bidders must pass, the auction's or the config's
+    pub bid_gate: Option<BidGate>,
+    /// The highest bidder's max if they're proxy bidding
+    pub proxy_bid: Option<ProxyBid>,
+}
+
+/// NFT auction loop
+#[cw_serde]
+pub enum AuctionState {
+    /// No live auction & no pending auctions, waiting for a submission to pass curation
+    Idle,
+    /// Taking bids, stays live past its end time until ConcludeAuction or Crank settles it
+    Live,
+}
+
+impl fmt::Display for AuctionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuctionState::Idle => write!(f, "idle"),
+            AuctionState::Live => write!(f, "live"),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum SubmissionStatus {
+    /// Within the vote period
+    Voting,
+    /// Vote period ended without enough votes
+    Expired,
+    /// Pending auction at this queue position (0 is next)
+    Queued { position: u64 },
+    /// Live NFT auction
+    Live,
+    /// Sold to the winning bidder
+    Settled { winning_bid: u128 },
+    /// Dropped by an emergency cancel
+    Cancelled,
+    /// Dropped by the no-bid policy
+    Unsold,
+}
+
+#[cw_serde]
+pub struct PausedResponse {
+    pub paused: Vec<PauseScope>,
+}
+
+#[cw_serde]
+pub struct BurnedResponse {
+    pub burned: Vec<Asset>,
+}
+
 #[cw_serde]
 pub struct PendingAuctionResponse {
     pub pending_auctions: Vec<Auction>,
@@ -165,6 +644,24 @@ pub enum Cw721QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Total number of tokens issued.
+    /// Return type: NumTokensResponse
+    NumTokens {},
+    /// Return type: MinterResponse
+    Minter {},
+    /// sg721 extension.
+    /// Return type: CollectionInfo<RoyaltyInfoResponse>
+    CollectionInfo {},
+}
+
+#[cw_serde]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct MinterResponse {
+    pub minter: Option<String>,
 }
 
 /////SG721
@@ -200,6 +697,22 @@ pub enum Sg721ExecuteMsg {
         recipient: String,
         token_id: String,
     },
+    /// Update specific collection info fields
+    UpdateCollectionInfo {
+        collection_info: UpdateCollectionInfoMsg<RoyaltyInfoResponse>,
+    },
+    /// Freeze collection info from further updates
+    FreezeCollectionInfo,
+}
+
+#[cw_serde]
+pub struct UpdateCollectionInfoMsg<T> {
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub external_link: Option<Option<String>>,
+    pub explicit_content: Option<bool>,
+    pub royalty_info: Option<Option<T>>,
+    pub creator: Option<String>,
 }
 
 //SG2
@@ -1,8 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cw_storage_plus::{Item, Map};
-use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cosmwasm_std::{to_json_binary, Addr, Decimal, StdResult, Uint128};
 
-use crate::msgs::Config;
+use crate::msgs::{Asset, AuctionState, BidGate, Config, PauseScope, SubmissionStatus};
 
 
 #[cw_serde]
@@ -11,6 +11,13 @@ pub struct Bid {
     pub amount: u128,
 }
 
+/// Max bid escrowed by the live NFT auction's highest bidder
+#[cw_serde]
+pub struct ProxyBid {
+    pub bidder: Addr,
+    pub max_bid: Uint128,
+}
+
 #[cw_serde]
 pub struct Votes {
     pub yes: u64,
@@ -23,35 +30,325 @@ pub struct SubmissionInfo {
     pub proceed_recipient: Addr,
     pub token_uri: String,
 }
+/// Optional on-chain metadata so submissions render without fetching the token_uri
+#[cw_serde]
+pub struct SubmissionMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub artist_name: Option<String>,
+    /// MIME type, e.g. image/png
+    pub content_type: Option<String>,
+    pub animation_url: Option<String>,
+    pub traits: Vec<Trait>,
+}
+
+impl SubmissionMetadata {
+    /// Metadata JSON for the minted NFT, the artist & content type are added as traits
+    pub fn to_nft_metadata(&self, token_uri: &str) -> NftMetadata {
+        let mut attributes = self.traits.clone();
+        if let Some(artist_name) = self.artist_name.clone() {
+            attributes.push(Trait { trait_type: String::from("artist"), value: artist_name });
+        }
+        if let Some(content_type) = self.content_type.clone() {
+            attributes.push(Trait { trait_type: String::from("content_type"), value: content_type });
+        }
+        NftMetadata {
+            name: self.title.clone(),
+            description: self.description.clone(),
+            image: token_uri.to_string(),
+            animation_url: self.animation_url.clone(),
+            attributes,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Trait {
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// Standard NFT metadata JSON
+#[cw_serde]
+pub struct NftMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: String,
+    pub animation_url: Option<String>,
+    pub attributes: Vec<Trait>,
+}
+
+impl NftMetadata {
+    /// Base64 JSON data URI, minted as the NFT's token_uri
+    pub fn to_token_uri(&self) -> StdResult<String> {
+        Ok(format!("data:application/json;base64,{}", to_json_binary(self)?.to_base64()))
+    }
+}
+
 #[cw_serde]
 pub struct SubmissionItem {
     pub submission: SubmissionInfo,
+    pub metadata: Option<SubmissionMetadata>,
     pub curators: Vec<Addr>,
+    /// Delegators whose votes were counted through their delegate
+    #[serde(default)]
+    pub delegated_voters: Vec<Addr>,
     pub votes: u64,
     pub submission_end_time: u64, //in seconds
 }
 
 #[cw_serde]
 pub struct Auction {
+    /// None for the first submission, which skips curation
+    pub submission_id: Option<u64>,
     pub submission_info: SubmissionItem,
-    pub bids: Vec<Bid>,
+    /// Set when the auction goes live, keys its bid history
+    pub auction_id: u64,
+    pub bid_count: u64,
     pub highest_bid: Bid,
     pub auction_end_time: u64, //in seconds
+    /// Minimum first bid
+    pub reserve_price: u128,
+    /// Times the auction was extended without bids
+    pub extensions: u64,
+    /// Overrides the config bid gate
+    pub bid_gate: Option<BidGate>,
+}
+
+/// Bid denom ledgers
+#[cw_serde]
+#[derive(Default)]
+pub struct Treasury {
+    /// Escrowed bids on the live NFT auction
+    pub escrowed_bids: Uint128,
+    /// Submission costs for the next bid asset auction, pooled once their submission is resolved
+    pub submission_fees: Uint128,
+    /// Submission costs held until their submission sells, expires or is dropped
+    #[serde(default)]
+    pub escrowed_submission_fees: Uint128,
+    /// Incentive share of NFT sales for the next bid asset auction
+    pub incentive_share: Uint128,
+    /// Donations for the next bid asset auction, per donor & asset
+    pub donations: Vec<Donation>,
+}
+
+impl Treasury {
+    /// Bid denom total for the next bid asset auction, excluding donations
+    pub fn pool(&self) -> Uint128 {
+        self.submission_fees + self.incentive_share
+    }
+}
+
+#[cw_serde]
+pub struct Donation {
+    pub donor: Addr,
+    pub asset: Asset,
 }
 
 #[cw_serde]
 pub struct BidAssetAuction {
-    pub auctioned_asset: Coin,
+    pub auctioned_asset: Vec<Asset>,
     pub highest_bid: Bid,
+    pub auction_end_time: u64, //in seconds
 }
 
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const SUBMISSIONS: Map<u64, SubmissionItem> = Map::new("submissions");
 pub const PENDING_AUCTION: Item<Vec<Auction>> = Item::new("pending_auctions");
 pub const NFT_AUCTION: Item<Auction> = Item::new("current_auction");
+pub const PROXY_BID: Item<ProxyBid> = Item::new("proxy_bid");
+pub const NEXT_AUCTION_ID: Item<u64> = Item::new("next_auction_id");
+//ustars held for mint fees
+pub const MINT_RESERVE: Item<Uint128> = Item::new("mint_reserve");
+//Bid history per (auction_id, seq)
+pub const BIDS: Map<(u64, u64), Bid> = Map::new("bids");
+pub const AUCTION_STATE: Item<AuctionState> = Item::new("auction_state");
 pub const WINNING_BIDDER: Item<String> = Item::new("winning_nft_bidder");
 pub const ASSET_AUCTION: Item<BidAssetAuction> = Item::new("current_bid_asset_auction");
+pub const TREASURY: Item<Treasury> = Item::new("treasury");
+//Sold pieces per proceed recipient, used to split royalties
+pub const ROYALTY_SHARES: Map<Addr, u64> = Map::new("royalty_shares");
+pub const ALLOWLIST: Map<Addr, ()> = Map::new("allowlist");
+//Total burned per asset, keyed by denom or cw20 address
+pub const BURNED: Map<String, Asset> = Map::new("burned");
 
+pub struct SubmissionIndexes<'a> {
+    pub submitter: MultiIndex<'a, Addr, SubmissionItem, u64>,
+    pub proceed_recipient: MultiIndex<'a, Addr, SubmissionItem, u64>,
+    pub submission_end_time: MultiIndex<'a, u64, SubmissionItem, u64>,
+}
 
-pub const OWNERSHIP_TRANSFER: Item<Addr> = Item::new("ownership_transfer");
\ No newline at end of file
+impl<'a> IndexList<SubmissionItem> for SubmissionIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SubmissionItem>> + '_> {
+        let v: Vec<&dyn Index<SubmissionItem>> = vec![&self.submitter, &self.proceed_recipient, &self.submission_end_time];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Submissions being voted on, keyed by submission ID
+pub fn submissions<'a>() -> IndexedMap<'a, u64, SubmissionItem, SubmissionIndexes<'a>> {
+    let indexes = SubmissionIndexes {
+        submitter: MultiIndex::new(|submission| submission.submission.submitter.clone(), "submissions", "submissions__submitter"),
+        proceed_recipient: MultiIndex::new(|submission| submission.submission.proceed_recipient.clone(), "submissions", "submissions__proceed_recipient"),
+        submission_end_time: MultiIndex::new(|submission| submission.submission_end_time, "submissions", "submissions__submission_end_time"),
+    };
+    IndexedMap::new("submissions", indexes)
+}
+
+/// Artist profile & track record, keyed by submitter
+#[cw_serde]
+#[derive(Default)]
+pub struct ArtistProfile {
+    pub display_name: Option<String>,
+    /// Preferred proceed recipient for submissions
+    pub payout_address: Option<Addr>,
+    pub submitted: u64,
+    /// Submissions that passed curation
+    pub curated: u64,
+    pub sold: u64,
+    /// Proceeds sent to proceed recipients, in the bid_denom
+    pub total_proceeds: Uint128,
+}
+
+pub struct ArtistProfileIndexes<'a> {
+    pub total_proceeds: MultiIndex<'a, u128, ArtistProfile, Addr>,
+}
+
+impl<'a> IndexList<ArtistProfile> for ArtistProfileIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ArtistProfile>> + '_> {
+        let v: Vec<&dyn Index<ArtistProfile>> = vec![&self.total_proceeds];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Artist profiles keyed by submitter, indexed by total proceeds for the leaderboard
+pub fn artist_profiles<'a>() -> IndexedMap<'a, Addr, ArtistProfile, ArtistProfileIndexes<'a>> {
+    let indexes = ArtistProfileIndexes {
+        total_proceeds: MultiIndex::new(|profile| profile.total_proceeds.u128(), "artist_profiles", "artist_profiles__total_proceeds"),
+    };
+    IndexedMap::new("artist_profiles", indexes)
+}
+
+/// Curator track record & rewards
+#[cw_serde]
+#[derive(Default)]
+pub struct CuratorStats {
+    /// Weight for curator rewards
+    pub score: u64,
+    /// Votes on pieces that sold above the curator_hit_price
+    pub hits: u64,
+    /// Votes on pieces that expired, didn't sell or sold at or below the curator_hit_price
+    pub misses: u64,
+    /// Unclaimed rewards in the incentive denom
+    pub pending_rewards: Uint128,
+    /// Reward index at the last update
+    pub reward_index: Decimal,
+}
+
+/// Curator rewards shared by score
+#[cw_serde]
+#[derive(Default)]
+pub struct CuratorRewards {
+    pub total_score: u64,
+    /// Rewards per score point, only increases
+    pub reward_index: Decimal,
+    /// Rewards owed to curators in the incentive denom
+    pub unclaimed: Uint128,
+}
+
+pub const CURATOR_STATS: Map<Addr, CuratorStats> = Map::new("curator_stats");
+pub const CURATOR_REWARDS: Item<CuratorRewards> = Item::new("curator_rewards");
+/// Privileged voter, e.g. a founder or partner DAO
+#[cw_serde]
+pub struct FreeVoter {
+    /// Votes per submission
+    pub weight: u64,
+    /// Block time in seconds the allowance ends, None never expires
+    pub expiration: Option<u64>,
+}
+
+/// Voters that vote & submit without holding the collection
+pub const FREE_VOTERS: Map<Addr, FreeVoter> = Map::new("free_voters");
+/// Delegate of each delegator's curation votes
+pub const DELEGATIONS: Map<Addr, Addr> = Map::new("delegations");
+/// Delegators of each delegate
+pub const DELEGATORS: Map<Addr, Vec<Addr>> = Map::new("delegators");
+pub const OWNERSHIP_TRANSFER: Item<Addr> = Item::new("ownership_transfer");
+pub const PAUSED: Item<Vec<PauseScope>> = Item::new("paused");
+//Escrowed submission fees of unresolved submissions, refunded if the no-bid policy drops the submission
+pub const SUBMISSION_FEES: Map<u64, Uint128> = Map::new("submission_fees");
+/// Final status of submissions that left the queue, keyed by submission ID
+pub const SUBMISSION_OUTCOMES: Map<u64, SubmissionStatus> = Map::new("submission_outcomes");
+
+/// v0.1.0 storage layout, converted by migrate
+pub mod legacy {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Addr, Coin, Decimal};
+    use cw_storage_plus::{Item, Map};
+
+    use super::{Bid, SubmissionInfo};
+
+    pub const CONTRACT_VERSION: &str = "0.1.0";
+
+    #[cw_serde]
+    pub struct Config {
+        pub owner: Addr,
+        pub free_vote_addr: Addr,
+        pub bid_denom: String,
+        pub minimum_outbid: Decimal,
+        pub incentive_denom: Option<String>,
+        pub incentive_bid_percent: Decimal,
+        pub current_submission_id: u64,
+        pub sg721_addr: String,
+        pub minter_addr: String,
+        pub mint_cost: u128,
+        pub submission_cost: u128,
+        pub submission_limit: u64,
+        pub submission_total: u64,
+        pub submission_vote_period: u64,
+        pub curation_threshold: Decimal,
+        pub auction_period: u64,
+    }
+
+    #[cw_serde]
+    pub struct SubmissionItem {
+        pub submission: SubmissionInfo,
+        pub curators: Vec<Addr>,
+        pub votes: u64,
+        pub submission_end_time: u64,
+    }
+
+    #[cw_serde]
+    pub struct Auction {
+        pub submission_info: SubmissionItem,
+        pub bids: Vec<Bid>,
+        pub highest_bid: Bid,
+        pub auction_end_time: u64,
+    }
+
+    /// Concluded with the NFT auction, the auctioned asset is the bid_denom
+    #[cw_serde]
+    pub struct BidAssetAuction {
+        pub auctioned_asset: Coin,
+        pub highest_bid: Bid,
+    }
+
+    impl From<SubmissionItem> for super::SubmissionItem {
+        fn from(item: SubmissionItem) -> Self {
+            super::SubmissionItem {
+                submission: item.submission,
+                metadata: None,
+                curators: item.curators,
+                delegated_voters: vec![],
+                votes: item.votes,
+                submission_end_time: item.submission_end_time,
+            }
+        }
+    }
+
+    pub const CONFIG: Item<Config> = Item::new("config");
+    pub const SUBMISSIONS: Map<u64, SubmissionItem> = Map::new("submissions");
+    pub const PENDING_AUCTION: Item<Vec<Auction>> = Item::new("pending_auctions");
+    pub const NFT_AUCTION: Item<Auction> = Item::new("current_auction");
+    pub const ASSET_AUCTION: Item<BidAssetAuction> = Item::new("current_bid_asset_auction");
+}
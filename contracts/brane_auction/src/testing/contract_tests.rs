@@ -1,26 +1,78 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::contracts::{query, instantiate, execute};
-    use crate::msgs::{Config, ExecuteMsg, InstantiateMsg, PendingAuctionResponse, QueryMsg, SubmissionsResponse};
-    use crate::state::{Auction, Bid, BidAssetAuction, SubmissionInfo, SubmissionItem};
+    use crate::contracts::{query, instantiate, execute, migrate, reply};
+    use crate::msgs::{ArtistDiscount, ArtistProfileResponse, ArtistProfilesResponse, Asset, AssetInfo, AuctionState, BaseMinterConfig, BaseMinterConfigResponse, BaseMinterExecuteMsg, BidGate, BidHistoryResponse, BurnStrategy, BurnedResponse, CollectionInfo, CollectionParams, Config, CuratorStatsResponse, Cw20HookMsg, FreeVoterResponse, FreeVotersResponse, Cw721QueryMsg, ExecuteMsg, InstantiateMsg, LiveNftAuctionResponse, MigrateMsg, MinterResponse, MintFeeSource, MintReserveResponse, NoBidPolicy, PauseScope, PausedResponse, PendingAuctionResponse, QueryMsg, BidAssetAuctionResponse, RoyaltyInfoResponse, RoyaltyPolicy, Sg2ExecuteMsg, Sg721ExecuteMsg, Sg721InstantiateMsg, SubmissionStatus, SubmissionsResponse, TokensResponse, TreasuryResponse, UpdateCollectionInfoMsg, VotingPowerResponse};
+    use crate::state::{legacy, ArtistProfile, Auction, Bid, BidAssetAuction, CuratorStats, Donation, FreeVoter, NftMetadata, ProxyBid, SubmissionInfo, SubmissionItem, SubmissionMetadata, Trait};
 
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{coin, from_json, Addr, Coin, CosmosMsg, Decimal, SubMsg, Uint128, WasmMsg};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{coin, from_json, to_json_binary, to_json_string, Addr, BankMsg, ContractResult, CosmosMsg, Decimal, Empty, Event, OwnedDeps, Reply, SubMsg, SubMsgResponse, SubMsgResult, SystemError, SystemResult, Uint128, WasmMsg, WasmQuery};
+    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+    /// Mock deps with a paired base minter & sg721 created by the mock contract
+    fn mock_dependencies_with_collection() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(|query| {
+            let response = match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == "some_minter_address" => {
+                    to_json_binary(&BaseMinterConfigResponse {
+                        collection_address: String::from("some_sg721_address"),
+                        config: BaseMinterConfig {
+                            factory: Addr::unchecked("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+                            collection_code_id: 180,
+                            mint_price: coin(0, "ustars"),
+                            extension: Empty {},
+                        },
+                    })
+                },
+                WasmQuery::Smart { contract_addr, msg } if contract_addr == "some_sg721_address" => {
+                    match from_json(msg) {
+                        //Only holder0000 holds an NFT
+                        Ok(Cw721QueryMsg::Tokens { owner, .. }) if owner == "holder0000" => to_json_binary(&TokensResponse { tokens: vec![String::from("1")] }),
+                        //whale0000 holds 150 NFTs, paged like the sg721
+                        Ok(Cw721QueryMsg::Tokens { owner, start_after, limit }) if owner == "whale0000" => {
+                            let start = start_after.map(|token_id| token_id.parse::<u64>().unwrap()).unwrap_or(0);
+                            let tokens = (start + 1..=150).take(limit.unwrap_or(10) as usize).map(|token_id| token_id.to_string()).collect();
+                            to_json_binary(&TokensResponse { tokens })
+                        },
+                        Ok(Cw721QueryMsg::Tokens { .. }) => to_json_binary(&TokensResponse { tokens: vec![] }),
+                        Ok(Cw721QueryMsg::Minter {}) => to_json_binary(&MinterResponse {
+                            minter: Some(String::from("some_minter_address")),
+                        }),
+                        Ok(Cw721QueryMsg::CollectionInfo {}) => to_json_binary(&CollectionInfo::<RoyaltyInfoResponse> {
+                            creator: mock_env().contract.address.to_string(),
+                            description: String::from("description"),
+                            image: String::from("ipfs://imageFolderCID/collection.png"),
+                            external_link: None,
+                            explicit_content: None,
+                            start_trading_time: None,
+                            royalty_info: None,
+                        }),
+                        _ => return SystemResult::Err(SystemError::NoSuchContract { addr: contract_addr.clone() }),
+                    }
+                },
+                WasmQuery::Smart { contract_addr, .. } => return SystemResult::Err(SystemError::NoSuchContract { addr: contract_addr.clone() }),
+                _ => return SystemResult::Err(SystemError::UnsupportedRequest { kind: String::from("wasm") }),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        });
+        deps
+    }
 
     #[test]
     fn submit_nft(){
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -36,32 +88,39 @@ mod tests {
         let query_msg = QueryMsg::LiveNftAuction { };
         let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
 
-        let resp: Auction = from_json(&res).unwrap();
+        let resp: Auction = from_json::<LiveNftAuctionResponse>(&res).unwrap().auction.unwrap();
         assert_eq!(resp, Auction {
+            submission_id: None,
             submission_info: SubmissionItem {
                 submission: SubmissionInfo {
                     submitter: Addr::unchecked("sender88"),
                     proceed_recipient: Addr::unchecked("proceed_recipient0000"),
                     token_uri: String::from("ipfs://imageFolderCID/1.png"),
                 },
+                metadata: None,
                 curators: vec![],
+                delegated_voters: vec![],
                 votes: 0u64,
                 submission_end_time: 1572402219,
             },
-            bids: vec![],
+            auction_id: 0,
+            bid_count: 0,
             highest_bid: Bid {
                 bidder: Addr::unchecked(""),
                 amount: 0u128,
             },
             auction_end_time: 1571883819,
-        
+            reserve_price: 0,
+            extensions: 0,
+            bid_gate: None,
         } );
 
 
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            metadata: None,
         };
         let _res = execute(
             deps.as_mut(),
@@ -72,8 +131,9 @@ mod tests {
         .unwrap();
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/2.png"),
+            metadata: None,
         };
         let _res = execute(
             deps.as_mut(),
@@ -84,8 +144,9 @@ mod tests {
         .unwrap();
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/3.png"),
+            metadata: None,
         };
         let _res = execute(
             deps.as_mut(),
@@ -120,17 +181,18 @@ mod tests {
 
     #[test]
     fn curate_nft(){        
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -144,8 +206,9 @@ mod tests {
 
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            metadata: None,
         };
         //Submission 1
         let _res = execute(
@@ -203,17 +266,18 @@ mod tests {
     #[test]
     fn bid_for_nft(){
 
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -226,7 +290,7 @@ mod tests {
         let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
 
         //Bid for NFT
-        let bid_msg = ExecuteMsg::BidForNft { };
+        let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
         let _res = execute(
             deps.as_mut(),
             mock_env(),
@@ -240,47 +304,59 @@ mod tests {
         let query_msg = QueryMsg::LiveNftAuction { };
         let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
 
-        let resp: Auction = from_json(&res).unwrap();
+        let resp: Auction = from_json::<LiveNftAuctionResponse>(&res).unwrap().auction.unwrap();
         assert_eq!(resp, Auction {
+            submission_id: None,
             submission_info: SubmissionItem {
                 submission: SubmissionInfo {
                     submitter: Addr::unchecked("sender88"),
                     proceed_recipient: Addr::unchecked("proceed_recipient0000"),
                     token_uri: String::from("ipfs://imageFolderCID/1.png"),
                 },
+                metadata: None,
                 curators: vec![],
+                delegated_voters: vec![],
                 votes: 0u64,
                 submission_end_time: 1572402219,
             },
-            bids: vec![
-                Bid {
-                bidder: Addr::unchecked("bidder0000"),
-                amount: 10000000,
-            }],
+            auction_id: 0,
+            bid_count: 1,
             highest_bid: Bid {
                 bidder: Addr::unchecked("bidder0000"),
                 amount: 10000000,
             },
             auction_end_time: 1571883819,
-        
+            reserve_price: 0,
+            extensions: 0,
+            bid_gate: None,
         } );
 
+
+        //Query bid history
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::BidHistory { auction_id: 0, limit: None, start_after: None }).unwrap();
+        let resp: BidHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bids, vec![Bid {
+            bidder: Addr::unchecked("bidder0000"),
+            amount: 10000000,
+        }]);
+
         /////Use integration tests to test that the replaced bid is sent back to the user
     }
     
     #[test]
     fn conclude_auction(){
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -289,13 +365,14 @@ mod tests {
             mint_cost: 1000u64,
         };
         //Instantiating contract
-        let v_info = mock_info("sender88", &[]);
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
         let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
 
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+            metadata: None,
         };
         //Submission 1
         let _res = execute(
@@ -337,7 +414,7 @@ mod tests {
         ).unwrap();
 
         //Bid for NFT works because auction was extended
-        let bid_msg = ExecuteMsg::BidForNft { };
+        let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
         let _res = execute(
             deps.as_mut(),
             mock_env(),
@@ -346,6 +423,19 @@ mod tests {
         )
         .unwrap();
 
+        //Query treasury: the unresolved submission's cost & the bid are escrowed
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp, TreasuryResponse {
+            escrowed_bids: Uint128::new(10_000_000),
+            submission_fees: Uint128::zero(),
+            escrowed_submission_fees: Uint128::new(10_000_000),
+            incentive_share: Uint128::zero(),
+            donations: vec![],
+            auctioned_asset: vec![],
+            escrowed_asset_bid: Uint128::zero(),
+        });
+
         //Push env to auction end time
         env.block.time = env.block.time.plus_seconds(86400 );
 
@@ -361,52 +451,68 @@ mod tests {
         let query_msg = QueryMsg::LiveNftAuction { };
         let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
 
-        let resp: Auction = from_json(&res).unwrap();
+        let resp: Auction = from_json::<LiveNftAuctionResponse>(&res).unwrap().auction.unwrap();
         assert_eq!(resp, Auction {
+            submission_id: Some(0),
             submission_info: SubmissionItem {
                 submission: SubmissionInfo {
                     submitter: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
                     proceed_recipient: Addr::unchecked("proceed_recipient0000"),
                     token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
                 },
+                metadata: None,
                 curators: vec![Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs")],
+                delegated_voters: vec![],
                 votes: 1u64,
                 submission_end_time: 1572402219,
             },
-            bids: vec![],
+            auction_id: 1,
+            bid_count: 0,
             highest_bid: Bid {
                 bidder: Addr::unchecked(""),
                 amount: 0,
             },
             auction_end_time: 1572056619,
-        
+            reserve_price: 0,
+            extensions: 0,
+            bid_gate: None,
         } );
 
         //Query to assert current bid asset auction
         let query_msg = QueryMsg::LiveBidAssetAuction { };
         let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
 
-        let resp: BidAssetAuction = from_json(&res).unwrap();
+        let resp: BidAssetAuction = from_json::<BidAssetAuctionResponse>(&res).unwrap().auction.unwrap();
         assert_eq!(resp, BidAssetAuction { 
-            auctioned_asset: Coin { denom: String::from("cdt"), amount: Uint128::new(10000000) },
+            //The sale's incentive share, the live submission's cost stays escrowed
+            auctioned_asset: vec![Asset { info: AssetInfo::NativeToken { denom: String::from("cdt") }, amount: Uint128::new(1_000_000) }],
             highest_bid: Bid { bidder: Addr::unchecked("cosmos2contract"), amount: 0u128 }, 
-        } )
+            auction_end_time: 1572056619,
+        } );
+
+        //Query treasury: escrow was released & the ledgers are in the auction
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::zero());
+        assert_eq!(resp.escrowed_submission_fees, Uint128::new(10_000_000));
+        assert_eq!(resp.incentive_share, Uint128::zero());
 
     }
     
     #[test]
     fn bid_for_bid_asset(){
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -421,8 +527,9 @@ mod tests {
         
         //Submit NFT
         let submit_msg = ExecuteMsg::SubmitNft {
-            proceed_recipient: String::from("proceed_recipient0000"),
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
             token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+            metadata: None,
         };
         //Submission 1
         let _res = execute(
@@ -443,7 +550,7 @@ mod tests {
         .unwrap();
     
         //Bid for NFT
-        let bid_msg = ExecuteMsg::BidForNft { };
+        let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
         let _res = execute(
             deps.as_mut(),
             mock_env(),
@@ -540,7 +647,7 @@ mod tests {
 
         
         // //Bid for NFT of 2nd Auction
-        // let bid_msg = ExecuteMsg::BidForNft { };
+        // let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
         // let _res = execute(
         //     deps.as_mut(),
         //     mock_env(),
@@ -573,20 +680,362 @@ mod tests {
         // });  
     }
 
+    #[test]
+    fn bid_with_cw20(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::Token { address: Addr::unchecked("cw20_bid") },
+            incentive_denom: Some(AssetInfo::Token { address: Addr::unchecked("cw20_meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Bid for NFT: Error - cw20 bids go through Receive
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cw20_bid")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        )
+        .unwrap_err();
+
+        //Bid for NFT: Error - wrong cw20
+        let receive_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: String::from("bidder0000"),
+            amount: Uint128::new(10_000_000),
+            msg: to_json_binary(&Cw20HookMsg::BidForNft { max_bid: None }).unwrap(),
+        });
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cw20_meme", &[]),
+            receive_msg.clone(),
+        )
+        .unwrap_err();
+
+        //Bid for NFT: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cw20_bid", &[]),
+            receive_msg,
+        )
+        .unwrap();
+
+        //Outbid returns the previous bid as a cw20 transfer
+        let receive_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: String::from("bidder0001"),
+            amount: Uint128::new(20_000_000),
+            msg: to_json_binary(&Cw20HookMsg::BidForNft { max_bid: None }).unwrap(),
+        });
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cw20_bid", &[]),
+            receive_msg,
+        )
+        .unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("cw20_bid"),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: String::from("bidder0000"),
+                amount: Uint128::new(10_000_000),
+            }).unwrap(),
+            funds: vec![],
+        }))]);
+
+        //Query live auction to confirm bid
+        let query_msg = QueryMsg::LiveNftAuction { };
+        let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+
+        let resp: Auction = from_json::<LiveNftAuctionResponse>(&res).unwrap().auction.unwrap();
+        assert_eq!(resp.highest_bid, Bid {
+            bidder: Addr::unchecked("bidder0001"),
+            amount: 20_000_000u128,
+        });
+    }
+
+    #[test]
+    fn burn_bid_asset_bids(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Submit & curate the next auction
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+
+        //Bid for NFT
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Conclude to start the bid asset auction
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+
+        //Bid for assets
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(5_000_000, "meme")]),
+            ExecuteMsg::BidForAssets { },
+        ).unwrap();
+        //Bid for NFT
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //TokenFactory burns use BankMsg::Burn for denoms the contract didn't create & curators share half of the bid
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: Some(BurnStrategy::TokenFactory),
+                guardian: None,
+                no_bid_policy: None,
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: Some(Decimal::percent(50)),
+                curator_hit_price: None,
+            },
+        ).unwrap();
+
+        //Conclude to burn the winning bid asset bid, the curator of the sold piece gets half
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert!(res.messages.contains(&SubMsg::new(CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(2_500_000, "meme")],
+        }))));
+
+        //Query burned totals
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Burned { }).unwrap();
+        let resp: BurnedResponse = from_json(&res).unwrap();
+        assert_eq!(resp.burned, vec![
+            Asset { info: AssetInfo::NativeToken { denom: String::from("meme") }, amount: Uint128::new(2_500_000) },
+        ]);
+    }
+
+    #[test]
+    fn bid_asset_auction_timer(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Escrowed NFT bid isn't auctioned
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        //Unresolved submission cost stays escrowed
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        //Donation goes to the bid asset auction
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+
+        //Start the bid asset auction without concluding the NFT auction
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuction = from_json::<BidAssetAuctionResponse>(&res).unwrap().auction.unwrap();
+        assert_eq!(resp, BidAssetAuction { 
+            auctioned_asset: vec![Asset { info: AssetInfo::NativeToken { denom: String::from("cdt") }, amount: Uint128::new(10_000_000) }],
+            highest_bid: Bid { bidder: Addr::unchecked("cosmos2contract"), amount: 0u128 }, 
+            auction_end_time: 1571883819,
+        });
+
+        //Conclude: Error - still live
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap_err();
+
+        //Bid in the soft close window extends the auction
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400 - 60);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(5_000_000, "meme")]),
+            ExecuteMsg::BidForAssets { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuction = from_json::<BidAssetAuctionResponse>(&res).unwrap().auction.unwrap();
+        assert_eq!(resp.auction_end_time, env.block.time.seconds() + 600);
+
+        //Conclude: Error - extended past the original end time
+        env.block.time = env.block.time.plus_seconds(60);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap_err();
+
+        //Conclude: Success even though the NFT auction had no conclusion
+        env.block.time = env.block.time.plus_seconds(600);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+        assert_eq!(res.messages, vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: String::from("bidder0000"),
+                amount: vec![coin(10_000_000, "cdt")],
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Burn {
+                amount: vec![coin(5_000_000, "meme")],
+            })),
+        ]);
+
+        //Bid: Error - auction ended
+        let _res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bidder0000", &[coin(5_000_000, "meme")]),
+            ExecuteMsg::BidForAssets { },
+        ).unwrap_err();
+    }
+
     #[test]
     fn update_config(){
         
-        let mut deps = mock_dependencies();
+        let mut deps = mock_dependencies_with_collection();
 
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
-            sg721_addr: Some(String::from("some_minter_address")),
+            sg721_addr: Some(String::from("some_sg721_address")),
             minter_addr: Some(String::from("some_minter_address")),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("meme")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -601,7 +1050,6 @@ mod tests {
         //Update config: Not owner
         let update_config_msg = ExecuteMsg::UpdateConfig {
             owner: None,
-            free_vote_addr: None,
             bid_denom: None,
             minimum_outbid: None,
             incentive_denom: None,
@@ -613,6 +1061,18 @@ mod tests {
             submission_vote_period: None,
             curation_threshold: None,
             auction_period: None,
+            asset_auction_period: None,
+            soft_close_period: None,
+            royalty_policy: None,
+            royalty_denom: None,
+            burn_strategy: None,
+            guardian: None,
+            no_bid_policy: None,
+            reserve_price: None,
+            mint_fee_source: None,
+            low_reserve_mints: None,
+            curator_reward_percent: None,
+            curator_hit_price: None,
         };
         let _res = execute(
             deps.as_mut(),
@@ -625,10 +1085,9 @@ mod tests {
         //Update config
         let update_config_msg = ExecuteMsg::UpdateConfig {
             owner: None,
-            free_vote_addr: Some(String::from("new_vote_addr")),
-            bid_denom: Some(String::from("different")),
+            bid_denom: Some(AssetInfo::NativeToken { denom: String::from("different") }),
             minimum_outbid: Some(Decimal::zero()),
-            incentive_denom: Some(String::from("different")),
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("different") }),
             // incentive_distribution_amount: Some(0u128),
             incentive_bid_percent: Some(Decimal::zero()),
             mint_cost: Some(0u128),
@@ -637,6 +1096,18 @@ mod tests {
             submission_vote_period: Some(0),
             curation_threshold: Some(Decimal::zero()),
             auction_period: Some(0),
+            asset_auction_period: Some(0),
+            soft_close_period: Some(0),
+            royalty_policy: None,
+            royalty_denom: None,
+            burn_strategy: None,
+            guardian: None,
+            no_bid_policy: Some(NoBidPolicy::Drop { max_extensions: 1 }),
+            reserve_price: Some(1_000u128),
+            mint_fee_source: None,
+            low_reserve_mints: None,
+            curator_reward_percent: None,
+            curator_hit_price: None,
         };
         let _res = execute(
             deps.as_mut(),
@@ -653,23 +1124,2937 @@ mod tests {
         let resp: Config = from_json(&res).unwrap();
         assert_eq!(resp, Config {
             owner: Addr::unchecked("sender88"),
-            free_vote_addr: Addr::unchecked("new_vote_addr"),
-            bid_denom: String::from("different"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("different") },
             minimum_outbid: Decimal::zero(),
-            incentive_denom: Some(String::from("different")),
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("different") }),
             // incentive_distribution_amount: 0u128,
             incentive_bid_percent: Decimal::zero(),
-            sg721_addr: String::from("some_minter_address"),
+            sg721_addr: String::from("some_sg721_address"),
             current_submission_id: 0u64,
             minter_addr: String::from("some_minter_address"),
             auction_period: 0u64,
+            asset_auction_period: 0u64,
+            soft_close_period: 0u64,
             curation_threshold: Decimal::zero(),
             submission_cost: 0u128,
             submission_limit: 0u64,
             submission_total: 0u64,
             submission_vote_period: 0u64,
             mint_cost: 0u128,
+            royalty_policy: RoyaltyPolicy::BidAssetAuction,
+            royalty_denom: String::from("ustars"),
+            burn_strategy: BurnStrategy::Burn,
+            guardian: None,
+            no_bid_policy: NoBidPolicy::Drop { max_extensions: 1 },
+            reserve_price: 1_000u128,
+            bid_gate: None,
+            mint_fee_source: MintFeeSource::Reserve,
+            low_reserve_mints: 7,
+            artist_discount: None,
+            curator_reward_percent: Decimal::zero(),
+            curator_hit_price: 0,
         } );
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn royalties(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(1_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Update royalty info: Not owner
+        let royalty_msg = ExecuteMsg::UpdateRoyaltyInfo {
+            royalty_info: Some(RoyaltyInfoResponse {
+                payment_address: String::from("treasury0000"),
+                share: Decimal::percent(5),
+            }),
+        };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            royalty_msg.clone(),
+        )
+        .unwrap_err();
+
+        //Update royalty info: Success
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            royalty_msg,
+        )
+        .unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_sg721_address"),
+            msg: to_json_binary(&Sg721ExecuteMsg::UpdateCollectionInfo {
+                collection_info: UpdateCollectionInfoMsg {
+                    description: None,
+                    image: None,
+                    external_link: None,
+                    explicit_content: None,
+                    royalty_info: Some(Some(RoyaltyInfoResponse {
+                        payment_address: String::from("treasury0000"),
+                        share: Decimal::percent(5),
+                    })),
+                    creator: None,
+                },
+            }).unwrap(),
+            funds: vec![],
+        }))]);
+
+        //Distribute royalties: Success, starts a bid asset auction in the royalty denom
+        deps.querier.update_balance("cosmos2contract", vec![coin(101_000, "ustars")]);
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::DistributeRoyalties { },
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().auctioned_asset, vec![Asset { info: AssetInfo::NativeToken { denom: String::from("ustars") }, amount: Uint128::new(100_000) }]);
+        
+        //Distribute royalties: Error - auctioned royalties are reserved
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::DistributeRoyalties { },
+        )
+        .unwrap_err();
+
+        //Route royalties to the treasury
+        let update_config_msg = ExecuteMsg::UpdateConfig {
+            owner: None,
+            bid_denom: None,
+            minimum_outbid: None,
+            incentive_denom: None,
+            incentive_bid_percent: None,
+            mint_cost: None,
+            submission_cost: None,
+            submission_limit: None,
+            submission_vote_period: None,
+            curation_threshold: None,
+            auction_period: None,
+            asset_auction_period: None,
+            soft_close_period: None,
+            royalty_policy: Some(RoyaltyPolicy::Treasury { addr: String::from("treasury0000") }),
+            royalty_denom: None,
+            burn_strategy: None,
+            guardian: None,
+            no_bid_policy: None,
+            reserve_price: None,
+            mint_fee_source: None,
+            low_reserve_mints: None,
+            curator_reward_percent: None,
+            curator_hit_price: None,
+        };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            update_config_msg,
+        )
+        .unwrap();
+
+        //Distribute royalties: Success, the next mint cost & auctioned royalties are held back
+        deps.querier.update_balance("cosmos2contract", vec![coin(201_000, "ustars")]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::DistributeRoyalties { },
+        )
+        .unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("treasury0000"),
+            amount: vec![coin(100_000, "ustars")],
+        }))]);
+    }
+
+    #[test]
+    fn donate(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Donate: Error - no assets
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[]),
+            ExecuteMsg::Donate { },
+        ).unwrap_err();
+
+        //Donate a basket of native assets
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(1_000, "cdt"), coin(2_000, "ustars")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+        //Donate a cw20
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cw20_token", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: String::from("donor0001"),
+                amount: Uint128::new(3_000),
+                msg: to_json_binary(&Cw20HookMsg::Donate { }).unwrap(),
+            }),
+        ).unwrap();
+        //Repeat donations are added to the donor's total
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.donations, vec![
+            Donation { donor: Addr::unchecked("donor0000"), asset: Asset { info: AssetInfo::NativeToken { denom: String::from("cdt") }, amount: Uint128::new(2_000) } },
+            Donation { donor: Addr::unchecked("donor0000"), asset: Asset { info: AssetInfo::NativeToken { denom: String::from("ustars") }, amount: Uint128::new(2_000) } },
+            Donation { donor: Addr::unchecked("donor0001"), asset: Asset { info: AssetInfo::Token { address: Addr::unchecked("cw20_token") }, amount: Uint128::new(3_000) } },
+        ]);
+
+        //Donations start the bid asset auction as one basket
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().auctioned_asset, vec![
+            Asset { info: AssetInfo::NativeToken { denom: String::from("cdt") }, amount: Uint128::new(2_000) },
+            Asset { info: AssetInfo::NativeToken { denom: String::from("ustars") }, amount: Uint128::new(2_000) },
+            Asset { info: AssetInfo::Token { address: Addr::unchecked("cw20_token") }, amount: Uint128::new(3_000) },
+        ]);
+        assert_eq!(resp.pending_donations, vec![]);
+
+        //Bid & conclude: the winner gets the whole basket
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(5_000, "meme")]),
+            ExecuteMsg::BidForAssets { },
+        ).unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+        assert_eq!(res.messages[0..3], vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: String::from("bidder0000"),
+                amount: vec![coin(2_000, "cdt")],
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: String::from("bidder0000"),
+                amount: vec![coin(2_000, "ustars")],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: String::from("cw20_token"),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: String::from("bidder0000"),
+                    amount: Uint128::new(3_000),
+                }).unwrap(),
+                funds: vec![],
+            })),
+        ]);
+
+        //Donate a basket that fills the next auction with the pool & royalty assets
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0002", &[coin(1_000, "token0"), coin(1_000, "token1"), coin(1_000, "token2"), coin(1_000, "token3"), coin(1_000, "token4"), coin(1_000, "token5"), coin(1_000, "token6")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+
+        //Donate: unbid assets roll over & count towards the limit
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("donor0002", &[coin(1_000, "token7")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("donor0002", &[coin(1_000, "token8")]),
+            ExecuteMsg::Donate { },
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid Asset: Bid asset auctions are limited to 10 assets");
+    }
+
+    #[test]
+    fn pause(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Pause: Error - not owner or guardian
+        let pause_msg = ExecuteMsg::Pause { scopes: vec![PauseScope::NftBids, PauseScope::Submissions] };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("guardian0000", &[]),
+            pause_msg.clone(),
+        ).unwrap_err();
+
+        //Set guardian
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: Some(String::from("guardian0000")),
+                no_bid_policy: None,
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
+            },
+        ).unwrap();
+
+        //Pause: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("guardian0000", &[]),
+            pause_msg,
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Paused { }).unwrap();
+        let resp: PausedResponse = from_json(&res).unwrap();
+        assert_eq!(resp.paused, vec![PauseScope::NftBids, PauseScope::Submissions]);
+
+        //Bid for NFT: Error - paused
+        let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            bid_msg.clone(),
+        ).unwrap_err();
+        //Submit NFT: Error - paused
+        let submit_msg = ExecuteMsg::SubmitNft {
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
+            token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+            metadata: None,
+        };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            submit_msg.clone(),
+        ).unwrap_err();
+        //Donations aren't paused
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+
+        //Unpause NFT bids
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::Unpause { scopes: Some(vec![PauseScope::NftBids]) },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            bid_msg,
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            submit_msg.clone(),
+        ).unwrap_err();
+
+        //Unpause everything
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("guardian0000", &[]),
+            ExecuteMsg::Unpause { scopes: None },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            submit_msg,
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Paused { }).unwrap();
+        let resp: PausedResponse = from_json(&res).unwrap();
+        assert_eq!(resp.paused, vec![]);
+    }
+
+    #[test]
+    fn emergency_cancel_auction(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Bid on the first auction
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        let cancel_msg = ExecuteMsg::EmergencyCancelAuction {
+            requeue: true,
+            reason: String::from("minter_addr is wrong"),
+        };
+        //Cancel: Error - within the grace period
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400 * 7);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender88", &[]),
+            cancel_msg.clone(),
+        ).unwrap_err();
+
+        //Cancel: Error - not owner or guardian
+        env.block.time = env.block.time.plus_seconds(86400);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            cancel_msg.clone(),
+        ).unwrap_err();
+
+        //Cancel: Success, the bid is refunded
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender88", &[]),
+            cancel_msg,
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0000"),
+            amount: vec![coin(10_000_000, "cdt")],
+        }))]);
+
+        //Requeued submission restarts since there are no other pending auctions
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: Auction = from_json::<LiveNftAuctionResponse>(&res).unwrap().auction.unwrap();
+        assert_eq!(resp.submission_info.submission.token_uri, String::from("ipfs://imageFolderCID/1.png"));
+        assert_eq!(resp.highest_bid.amount, 0u128);
+        assert_eq!(resp.auction_end_time, env.block.time.seconds() + 86400);
+
+        //Escrow is released
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::zero());
+
+        //Cancel without requeueing drops the auction
+        env.block.time = env.block.time.plus_seconds(86400 * 8);
+        let _res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("sender88", &[]),
+            ExecuteMsg::EmergencyCancelAuction {
+                requeue: false,
+                reason: String::from("bad token_uri"),
+            },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction, None);
+    }
+
+    #[test]
+    fn events(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+        let config: Config = from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config { }).unwrap()).unwrap();
+        assert_eq!(res.events, vec![Event::new("brane_config_updated")
+            .add_attribute("config", to_json_string(&config).unwrap())]);
+
+        //Submission
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        assert_eq!(res.events, vec![Event::new("brane_submission")
+            .add_attribute("submission_id", "0")
+            .add_attribute("submitter", "stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs")
+            .add_attribute("payment", "0")
+            .add_attribute("submission", r#"{"submission":{"submitter":"stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs","proceed_recipient":"proceed_recipient0000","token_uri":"ipfs://imageFolderCID/submission1.png"},"metadata":null,"curators":[],"delegated_voters":[],"votes":0,"submission_end_time":1572402219}"#)]);
+
+        //Vote
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+        assert_eq!(res.events, vec![Event::new("brane_vote")
+            .add_attribute("curator", "stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs")
+            .add_attribute("votes", "1")
+            .add_attribute("submission_ids", "[0]")
+            .add_attribute("passed", "[0]")]);
+
+        //Bid
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        assert_eq!(res.events, vec![Event::new("brane_bid")
+            .add_attribute("auction", "nft")
+            .add_attribute("bidder", "bidder0000")
+            .add_attribute("amount", "10000000")
+            .add_attribute("asset", r#"{"native_token":{"denom":"cdt"}}"#)
+            .add_attribute("auction_end_time", "1571883819")]);
+
+        //Settle the NFT auction, its incentive share starts the bid asset auction
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events, vec![
+            Event::new("brane_auction_settled")
+                .add_attribute("token_uri", "ipfs://imageFolderCID/1.png")
+                .add_attribute("proceed_recipient", "proceed_recipient0000")
+                .add_attribute("winner", "bidder0000")
+                .add_attribute("winning_bid", "10000000")
+                .add_attribute("proceeds", "9000000")
+                .add_attribute("incentive_share", "1000000")
+                .add_attribute("auction_end_time", "1571883819"),
+            Event::new("brane_asset_auction_settled")
+                .add_attribute("winning_bid", "0")
+                .add_attribute("sold", "[]")
+                .add_attribute("burned", "null")
+                .add_attribute("curator_rewards", "0")
+                .add_attribute("next_auction", r#"[{"info":{"native_token":{"denom":"cdt"}},"amount":"1000000"}]"#)
+                .add_attribute("next_auction_end_time", "1571970219"),
+        ]);
+        //The next pending auction is live while the mint is in flight
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Live);
+    }
+
+    #[test]
+    fn submission_queries(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //3 submissions from the free voter
+        let submit_msg = ExecuteMsg::SubmitNft {
+            proceed_recipient: Some(String::from("proceed_recipient0000")),
+            token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+            metadata: None,
+        };
+        for _ in 0..3 {
+            let _res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+                submit_msg.clone(),
+            ).unwrap();
+        }
+
+        //Query submissions by submitter
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionsBySubmitter { addr: String::from("artist0000"), limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions.len(), 0);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionsBySubmitter { addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"), limit: Some(2), start_after: Some(0) }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions.len(), 2);
+
+        //Query voting power & threshold, the free voter gets 1 vote
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VotingPower { addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs") }).unwrap();
+        let resp: VotingPowerResponse = from_json(&res).unwrap();
+        assert_eq!(resp, VotingPowerResponse { votes: 1, delegated_votes: 0, total: 1 });
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::PassingThreshold { }).unwrap();
+        let resp: u128 = from_json(&res).unwrap();
+        assert_eq!(resp, 1);
+
+        //Vote for submissions 0 & 1 to queue them
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::HasVoted { submission_id: 0, addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs") }).unwrap();
+        let resp: bool = from_json(&res).unwrap();
+        assert!(!resp);
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0, 1] },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::HasVoted { submission_id: 0, addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs") }).unwrap();
+        let resp: bool = from_json(&res).unwrap();
+        assert!(resp);
+
+        //Query statuses
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionStatus { id: 1 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Queued { position: 1 });
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionStatus { id: 2 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Voting);
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400 * 8);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 2 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Expired);
+
+        //Estimated start time: the live auction ends in a day & each auction lasts a day
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::EstimatedStartTime { queue_position: 1 }).unwrap();
+        let resp: u64 = from_json(&res).unwrap();
+        assert_eq!(resp, 1571797419 + 86400 * 2);
+
+        //Bid, settle the first auction & start submission 0
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Live);
+
+        //Settle submission 0
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Settled { winning_bid: 10_000_000 });
+        let res = query(deps.as_ref(), env, QueryMsg::SubmissionStatus { id: 1 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Live);
+    }
+
+    #[test]
+    fn submission_indexes(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Submission 0
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionsByRecipient { addr: String::from("proceed_recipient0000"), limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions.len(), 1);
+
+        //Submission 1 after submission 0's vote period
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400 * 8);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0001")),
+                token_uri: String::from("ipfs://imageFolderCID/submission2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+
+        //Voting on submission 1 prunes submission 0
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![1] },
+        ).unwrap();
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        let resp: SubmissionStatus = from_json(&res).unwrap();
+        assert_eq!(resp, SubmissionStatus::Expired);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Submissions { submission_id: None, limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions.len(), 0);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Config { }).unwrap();
+        let resp: Config = from_json(&res).unwrap();
+        assert_eq!(resp.submission_total, 0);
+    }
+
+    #[test]
+    fn live_auction_queries(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Query the first auction
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.time_remaining, 86400);
+        assert_eq!(resp.minimum_next_bid, Uint128::one());
+        assert!(!resp.can_conclude);
+        assert_eq!(resp.next_pending, None);
+
+        //Minimum next bid is 1% over the highest bid
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.minimum_next_bid, Uint128::new(10_100_001));
+
+        //No bid asset auction
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction, None);
+        assert!(!resp.can_conclude);
+
+        //Start the bid asset auction with a donation
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.minimum_next_bid, Uint128::one());
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(5_000, "meme")]),
+            ExecuteMsg::BidForAssets { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.minimum_next_bid, Uint128::new(5_050));
+
+        //Both auctions can be concluded once they end
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.time_remaining, 0);
+        assert!(resp.can_conclude);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        assert!(resp.can_conclude);
+
+        //Unless conclusion is paused
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::Pause { scopes: vec![PauseScope::Conclusion] },
+        ).unwrap();
+        let res = query(deps.as_ref(), env, QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert!(!resp.can_conclude);
+    }
+
+    #[test]
+    fn crank(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //The first auction is live
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Live);
+
+        //Crank: Error - nothing is due
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::Crank { },
+        ).unwrap_err();
+
+        //Bid & submit a piece that won't be curated
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+
+        //The ended auction is live until it's settled
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Live);
+
+        //Crank: Error - conclusion is paused
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::Pause { scopes: vec![PauseScope::Conclusion] },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::Crank { },
+        ).unwrap_err();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::Unpause { scopes: None },
+        ).unwrap();
+
+        //Crank settles the NFT auction, which starts the bid asset auction
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::Crank { },
+        ).unwrap();
+        assert_eq!(res.attributes[1].value, "bidder0000");
+        assert_eq!(res.attributes.last().unwrap().value, r#"["settle"]"#);
+        assert!(res.events.contains(&Event::new("brane_auction_state")
+            .add_attribute("from", "live")
+            .add_attribute("to", "idle")));
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Idle);
+
+        //Crank settles the bid asset auction & prunes the expired submission
+        env.block.time = env.block.time.plus_seconds(86400 * 7);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::Crank { },
+        ).unwrap();
+        assert_eq!(res.attributes[1].value, r#"["settle_bid_asset_auction","prune"]"#);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        assert_eq!(from_json::<SubmissionStatus>(&res).unwrap(), SubmissionStatus::Expired);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Config { }).unwrap();
+        assert_eq!(from_json::<Config>(&res).unwrap().submission_total, 0);
+    }
+
+    #[test]
+    fn no_bid_policy(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Drop after 1 extension & keep the bid asset auction live so submission fees aren't auctioned
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: Some(10),
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: Some(NoBidPolicy::Drop { max_extensions: 1 }),
+                reserve_price: Some(1_000u128),
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("donor0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::Donate { },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::ConcludeBidAssetAuction { },
+        ).unwrap();
+
+        //Submit with a fee & curate it into the queue
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+
+        //No bids: extend
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events[0], Event::new("brane_no_bid")
+            .add_attribute("token_uri", "ipfs://imageFolderCID/1.png")
+            .add_attribute("action", "extend")
+            .add_attribute("extensions", "1")
+            .add_attribute("reserve_price", "0")
+            .add_attribute("refund", "0")
+            .add_attribute("auction_end_time", "1571970219"));
+
+        //No bids: drop, the queued submission starts with the reserve price
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events[0].attributes[1].value, "drop");
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.clone().unwrap().submission_id, Some(0));
+        assert_eq!(resp.auction.unwrap().reserve_price, 1_000);
+        assert_eq!(resp.minimum_next_bid, Uint128::new(1_000));
+
+        //Bid: Error - below the reserve price
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(999, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+
+        //Lower the reserve by half on extension
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: Some(NoBidPolicy::LowerReserve { decrease: Decimal::percent(50) }),
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
+            },
+        ).unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events[0].attributes[1].value, "extend");
+        assert_eq!(res.events[0].attributes[3].value, "500");
+
+        //No bids past the max extensions: drop & refund the submission fee
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: Some(NoBidPolicy::Drop { max_extensions: 1 }),
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
+            },
+        ).unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            amount: vec![coin(10_000_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        assert_eq!(from_json::<SubmissionStatus>(&res).unwrap(), SubmissionStatus::Unsold);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Idle);
+
+        //Submit with a fee & curate it into the live auction
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/3.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![1] },
+        ).unwrap();
+
+        //No bids: extend, the bid asset auction ends without the live submission's fee
+        env.block.time = mock_env().block.time.plus_seconds(10 * 86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events[0].attributes[1].value, "extend");
+        assert_eq!(res.events[1].ty, "brane_asset_auction_settled");
+
+        //Another submission's fee is escrowed too
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(5_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/4.png"),
+                metadata: None,
+            },
+        ).unwrap();
+
+        //No bids: drop & refund, the fee stayed escrowed through the bid asset auction
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.events[0].attributes[1].value, "drop");
+        assert_eq!(res.events[0].attributes[4].value, "10000000");
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            amount: vec![coin(10_000_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submission_fees, Uint128::zero());
+        assert_eq!(resp.escrowed_submission_fees, Uint128::new(5_000_000));
+    }
+
+    #[test]
+    fn bid_gate(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Set gate: Error - unauthorized
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[]),
+            ExecuteMsg::SetBidGate { gate: Some(BidGate::Allowlist), live_auction_only: false },
+        ).unwrap_err();
+
+        //Allowlist all auctions
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetBidGate { gate: Some(BidGate::Allowlist), live_auction_only: false },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bid_gate, Some(BidGate::Allowlist));
+
+        //Bid: Error - not allowlisted
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+
+        //Bid: Success - allowlisted
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateAllowlist { add: vec![String::from("bidder0000")], remove: vec![] },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Allowlisted { addr: String::from("bidder0000") }).unwrap();
+        assert!(from_json::<bool>(&res).unwrap());
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //The live auction's gate overrides the config's
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetBidGate { gate: Some(BidGate::IncentiveBalance { min_balance: Uint128::new(100) }), live_auction_only: true },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bid_gate, Some(BidGate::IncentiveBalance { min_balance: Uint128::new(100) }));
+
+        //Bid: Error - incentive balance too low
+        deps.querier.update_balance("bidder0001", vec![coin(99, "meme")]);
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0001", &[coin(20_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+
+        //Bid: Success
+        deps.querier.update_balance("bidder0001", vec![coin(100, "meme")]);
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0001", &[coin(20_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Holders only, free voters need to hold NFTs too
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetBidGate { gate: Some(BidGate::Holders { min_nfts: 1 }), live_auction_only: true },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(30_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(30_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("holder0000", &[coin(30_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Holdings are counted past the sg721's page size
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetBidGate { gate: Some(BidGate::Holders { min_nfts: 150 }), live_auction_only: true },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("holder0000", &[coin(40_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap_err();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("whale0000", &[coin(40_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VotingPower { addr: String::from("whale0000") }).unwrap();
+        assert_eq!(from_json::<VotingPowerResponse>(&res).unwrap().votes, 150);
+    }
+
+    #[test]
+    fn proxy_bidding(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Proxy bid: Error - the max bid must be sent
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(9_999, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: Some(Uint128::new(10_000)) },
+        ).unwrap_err();
+
+        //Proxy bid: the first bid only needs 1
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: Some(Uint128::new(10_000)) },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().highest_bid.amount, 1);
+        assert_eq!(resp.proxy_bid, Some(ProxyBid { bidder: Addr::unchecked("bidder0000"), max_bid: Uint128::new(10_000) }));
+
+        //Bid under the max: the proxy counters & the bid is refunded
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0001", &[coin(5_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0001"),
+            amount: vec![coin(5_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().highest_bid, Bid { bidder: Addr::unchecked("bidder0000"), amount: 5_051 });
+
+        //Proxy bid over the max: takes the lead by the minimum outbid & the previous max is refunded
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0001", &[coin(20_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: Some(Uint128::new(20_000)) },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0000"),
+            amount: vec![coin(10_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().bid_count, 5);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::BidHistory { auction_id: 0, limit: None, start_after: Some(2) }).unwrap();
+        let resp: BidHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bids, vec![
+            Bid { bidder: Addr::unchecked("bidder0000"), amount: 10_000 },
+            Bid { bidder: Addr::unchecked("bidder0001"), amount: 10_101 },
+        ]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::new(20_000));
+
+        //Conclude: the unused escrow is refunded
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0001", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert!(res.messages.contains(&SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0001"),
+            amount: vec![coin(9_899, "cdt")],
+        }))));
+        let res = query(deps.as_ref(), env, QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::zero());
+    }
+
+    #[test]
+    fn leader_rebids(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Plain bid
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Leader re-bids: the bid becomes their max & the previous bid is refunded
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(15_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0000"),
+            amount: vec![coin(1_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        let auction = resp.auction.unwrap();
+        assert_eq!(auction.highest_bid, Bid { bidder: Addr::unchecked("bidder0000"), amount: 1_000 });
+        assert_eq!(auction.bid_count, 1);
+        assert_eq!(resp.proxy_bid, Some(ProxyBid { bidder: Addr::unchecked("bidder0000"), max_bid: Uint128::new(15_000) }));
+
+        //Leader re-bids: Error - must raise their max
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(15_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: Some(Uint128::new(15_000)) },
+        ).unwrap_err();
+
+        //Leader raises their max: the price doesn't move
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(20_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: Some(Uint128::new(20_000)) },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0000"),
+            amount: vec![coin(15_000, "cdt")],
+        }))]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        let auction = resp.auction.unwrap();
+        assert_eq!(auction.highest_bid, Bid { bidder: Addr::unchecked("bidder0000"), amount: 1_000 });
+        assert_eq!(auction.bid_count, 1);
+        assert_eq!(resp.proxy_bid, Some(ProxyBid { bidder: Addr::unchecked("bidder0000"), max_bid: Uint128::new(20_000) }));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::BidHistory { auction_id: 0, limit: None, start_after: None }).unwrap();
+        let resp: BidHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bids, vec![Bid { bidder: Addr::unchecked("bidder0000"), amount: 1_000 }]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::new(20_000));
+
+        //Outbid: the raised max counters
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0001", &[coin(5_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.auction.unwrap().highest_bid, Bid { bidder: Addr::unchecked("bidder0000"), amount: 5_051 });
+    }
+
+    #[test]
+    fn mint_reserve(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract without funding the reserve
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::MintReserve { }).unwrap();
+        assert_eq!(from_json::<MintReserveResponse>(&res).unwrap(), MintReserveResponse { reserve: Uint128::zero(), mints_remaining: 0 });
+
+        //Fund Mint Reserve: Error - only ustars
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("funder0000", &[coin(1_000, "cdt")]),
+            ExecuteMsg::FundMintReserve { },
+        ).unwrap_err();
+
+        //Bid & let the auction end
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+
+        //Conclude Auction: Error - the reserve can't cover the mint fee & the bid isn't ustars
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Custom Error val: Mint reserve can't cover the mint fee, use FundMintReserve & conclude again");
+        //The auction is still there to retry
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Live);
+
+        //Fund Mint Reserve: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("funder0000", &[coin(1_500, "ustars")]),
+            ExecuteMsg::FundMintReserve { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::MintReserve { }).unwrap();
+        assert_eq!(from_json::<MintReserveResponse>(&res).unwrap(), MintReserveResponse { reserve: Uint128::new(1_500), mints_remaining: 1 });
+
+        //Conclude Auction: Success, the reserve pays the fee & warns it's low
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert!(res.events.contains(&Event::new("brane_mint_reserve_low")
+            .add_attribute("reserve", "500")
+            .add_attribute("mints_remaining", "0")));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::MintReserve { }).unwrap();
+        assert_eq!(from_json::<MintReserveResponse>(&res).unwrap(), MintReserveResponse { reserve: Uint128::new(500), mints_remaining: 0 });
+
+        //Take the mint fee from ustars winning bids
+        let mut deps = mock_dependencies_with_collection();
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("ustars") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        let _res = instantiate(deps.as_mut(), mock_env(), mock_info("sender88", &[]), msg).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder0000", &[coin(101_000, "ustars")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Conclude Auction: Success, the empty reserve falls back to the winning bid & warns it's low
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.messages[1], SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("proceed_recipient0000"),
+            amount: vec![coin(90_000, "ustars")],
+        })));
+        assert!(res.events.contains(&Event::new("brane_mint_reserve_low")
+            .add_attribute("reserve", "0")
+            .add_attribute("mints_remaining", "0")));
+
+        //Take the mint fee from the winning bid before the reserve
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("funder0000", &[coin(1_000_000, "ustars")]),
+            ExecuteMsg::FundMintReserve { },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: None,
+                reserve_price: None,
+                mint_fee_source: Some(MintFeeSource::WinningBid),
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[coin(10_000_000, "ustars")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(101_000, "ustars")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+
+        //Conclude Auction: Success, proceeds are split after the mint fee
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.messages[1], SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("proceed_recipient0000"),
+            amount: vec![coin(90_000, "ustars")],
+        })));
+        assert!(!res.events.iter().any(|event| event.ty == "brane_mint_reserve_low"));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::MintReserve { }).unwrap();
+        assert_eq!(from_json::<MintReserveResponse>(&res).unwrap().reserve, Uint128::new(1_000_000));
+    }
+
+    #[test]
+    fn collection_admin(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let mut msg = InstantiateMsg {
+            sg721_code_id: Some(180),
+            collection_params: None,
+            start_trading_time: Some(mock_env().block.time.minus_seconds(1)),
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: None,
+            minter_addr: None,
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract: Error - start trading time in the past
+        let v_info = mock_info("sender88", &[coin(250_000_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info.clone(), msg.clone()).unwrap_err();
+
+        //Instantiating contract: Error - the contract isn't the collection creator
+        msg.start_trading_time = Some(mock_env().block.time.plus_seconds(86400));
+        msg.collection_params = Some(CollectionParams {
+            code_id: 180,
+            name: String::from("The International Brane Wave"),
+            symbol: String::from("BRANE"),
+            info: CollectionInfo {
+                creator: String::from("sender88"),
+                description: String::from("description"),
+                image: String::from("ipfs://imageFolderCID/collection.png"),
+                external_link: None,
+                explicit_content: None,
+                start_trading_time: None,
+                royalty_info: None,
+            },
+        });
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info.clone(), msg.clone()).unwrap_err();
+
+        //Instantiating contract: Success, the collection starts trading at the start trading time
+        msg.collection_params = None;
+        let res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                let Sg2ExecuteMsg::CreateMinter(create_msg) = from_json::<Sg2ExecuteMsg<Option<Sg721InstantiateMsg>>>(msg).unwrap();
+                assert_eq!(create_msg.collection_params.info.start_trading_time, Some(mock_env().block.time.plus_seconds(86400)));
+                assert_eq!(create_msg.collection_params.info.creator, mock_env().contract.address.to_string());
+            },
+            _ => panic!("Expected a CreateMinter msg"),
+        }
+
+        //Set the collection addresses
+        let mut deps = mock_dependencies_with_collection();
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        let _res = instantiate(deps.as_mut(), mock_env(), mock_info("sender88", &[]), msg).unwrap();
+
+        //Update Start Trading Time: Success, sent through the minter
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateStartTradingTime { start_trading_time: None },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_minter_address"),
+            msg: to_json_binary(&BaseMinterExecuteMsg::UpdateStartTradingTime(None)).unwrap(),
+            funds: vec![],
+        }))]);
+
+        //Update Collection Info: Error - invalid image
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateCollectionInfo {
+                description: None,
+                image: Some(String::from("not a url")),
+                external_link: None,
+                explicit_content: None,
+            },
+        ).unwrap_err();
+
+        //Update Collection Info: Success
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateCollectionInfo {
+                description: Some(String::from("new description")),
+                image: None,
+                external_link: Some(None),
+                explicit_content: None,
+            },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_sg721_address"),
+            msg: to_json_binary(&Sg721ExecuteMsg::UpdateCollectionInfo {
+                collection_info: UpdateCollectionInfoMsg {
+                    description: Some(String::from("new description")),
+                    image: None,
+                    external_link: Some(None),
+                    explicit_content: None,
+                    royalty_info: None,
+                    creator: None,
+                },
+            }).unwrap(),
+            funds: vec![],
+        }))]);
+
+        //Freeze Collection Info: Success
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::FreezeCollectionInfo { },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_sg721_address"),
+            msg: to_json_binary(&Sg721ExecuteMsg::FreezeCollectionInfo).unwrap(),
+            funds: vec![],
+        }))]);
+    }
+
+    #[test]
+    fn verify_collection(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let mut msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: None,
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        let v_info = mock_info("sender88", &[]);
+
+        //Instantiating contract: Error - missing the sg721
+        let err = instantiate(deps.as_mut(), mock_env(), v_info.clone(), msg.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "Custom Error val: Pass a sg721_code_id or both the minter_addr & sg721_addr");
+
+        //Instantiating contract: Error - the minter mints to another sg721
+        msg.sg721_addr = Some(String::from("other_sg721_address"));
+        let err = instantiate(deps.as_mut(), mock_env(), v_info.clone(), msg.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "Custom Error val: The minter doesn't mint to the sg721");
+
+        //Instantiating contract: Error - not a minter
+        msg.sg721_addr = Some(String::from("some_sg721_address"));
+        msg.minter_addr = Some(String::from("other_minter_address"));
+        let err = instantiate(deps.as_mut(), mock_env(), v_info.clone(), msg.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "Custom Error val: Failed to query the minter's config");
+
+        //Instantiating contract: Error - the contract isn't the collection creator
+        msg.minter_addr = Some(String::from("some_minter_address"));
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("other_contract");
+        let err = instantiate(deps.as_mut(), env, v_info.clone(), msg.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "Custom Error val: The contract must be the collection creator to mint");
+
+        //Instantiating contract: Success
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+    }
+
+    #[test]
+    fn submission_metadata(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        let metadata = SubmissionMetadata {
+            title: Some(String::from("Brane Wave #2")),
+            description: Some(String::from("A wave")),
+            artist_name: Some(String::from("artist")),
+            content_type: Some(String::from("image/png")),
+            animation_url: None,
+            traits: vec![Trait { trait_type: String::from("background"), value: String::from("blue") }],
+        };
+
+        //Submit NFT: Error - invalid metadata
+        for invalid_metadata in [
+            SubmissionMetadata { title: Some("a".repeat(101)), ..metadata.clone() },
+            SubmissionMetadata { description: Some("a".repeat(1001)), ..metadata.clone() },
+            SubmissionMetadata { artist_name: Some("a".repeat(65)), ..metadata.clone() },
+            SubmissionMetadata { content_type: Some(String::from("png")), ..metadata.clone() },
+            SubmissionMetadata { animation_url: Some(String::from("not a url")), ..metadata.clone() },
+            SubmissionMetadata { traits: vec![Trait { trait_type: String::from("background"), value: String::from("blue") }; 17], ..metadata.clone() },
+            SubmissionMetadata { traits: vec![Trait { trait_type: String::from("background"), value: String::new() }], ..metadata.clone() },
+        ] {
+            let _res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+                ExecuteMsg::SubmitNft {
+                    proceed_recipient: Some(String::from("proceed_recipient0000")),
+                    token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                    metadata: Some(invalid_metadata),
+                },
+            ).unwrap_err();
+        }
+
+        //Submit NFT: Error - with metadata the token_uri is the image, not a metadata JSON
+        for metadata_uri in ["ipfs://imageFolderCID/2.json", "data:application/json;base64,e30="] {
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+                ExecuteMsg::SubmitNft {
+                    proceed_recipient: Some(String::from("proceed_recipient0000")),
+                    token_uri: String::from(metadata_uri),
+                    metadata: Some(metadata.clone()),
+                },
+            ).unwrap_err();
+            assert_eq!(err.to_string(), "Custom Error val: Submissions with metadata use the image as the token_uri, not a metadata JSON");
+        }
+
+        //Submit NFT: Success, the metadata is stored with the submission
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: Some(metadata.clone()),
+            },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Submissions { submission_id: Some(0), limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions[0].metadata, Some(metadata.clone()));
+
+        //Curate the submission into the queue
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+
+        //Sell & mint the first submission, which has no metadata
+        let mut env = mock_env();
+        let mint_reply = |token_id: &str| Reply {
+            id: 2,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![Event::new("wasm").add_attribute("token_id", token_id)],
+                data: None,
+            }),
+        };
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        assert_eq!(res.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_minter_address"),
+            msg: to_json_binary(&BaseMinterExecuteMsg::Mint { token_uri: String::from("ipfs://imageFolderCID/1.png") }).unwrap(),
+            funds: vec![coin(1_000, "ustars")],
+        }));
+        let _res = reply(deps.as_mut(), env.clone(), mint_reply("1")).unwrap();
+
+        //Sell & mint the curated submission with its metadata JSON as the token_uri
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone0000", &[]),
+            ExecuteMsg::ConcludeAuction { },
+        ).unwrap();
+        let mint_msg = res.messages[0].msg.clone();
+        let nft_metadata = NftMetadata {
+            name: Some(String::from("Brane Wave #2")),
+            description: Some(String::from("A wave")),
+            image: String::from("ipfs://imageFolderCID/2.png"),
+            animation_url: None,
+            attributes: vec![
+                Trait { trait_type: String::from("background"), value: String::from("blue") },
+                Trait { trait_type: String::from("artist"), value: String::from("artist") },
+                Trait { trait_type: String::from("content_type"), value: String::from("image/png") },
+            ],
+        };
+        assert_eq!(mint_msg, CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: String::from("some_minter_address"),
+            msg: to_json_binary(&BaseMinterExecuteMsg::Mint {
+                token_uri: format!("data:application/json;base64,{}", to_json_binary(&nft_metadata).unwrap().to_base64()),
+            }).unwrap(),
+            funds: vec![coin(1_000, "ustars")],
+        }));
+    }
+
+    #[test]
+    fn artist_profiles(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Update Artist Profile: Error - display name too long
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[]),
+            ExecuteMsg::UpdateArtistProfile { display_name: Some("a".repeat(65)), payout_address: None },
+        ).unwrap_err();
+
+        //Update Artist Profile: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[]),
+            ExecuteMsg::UpdateArtistProfile { display_name: Some(String::from("Artist")), payout_address: Some(String::from("payout0000")) },
+        ).unwrap();
+
+        //Update Artist Profile: Success, omitted fields are left as is
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[]),
+            ExecuteMsg::UpdateArtistProfile { display_name: None, payout_address: None },
+        ).unwrap();
+
+        //Submit NFT without a proceed recipient & curate it, proceeds go to the payout address
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[coin(10_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: None,
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Submissions { submission_id: Some(0), limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions[0].submission.proceed_recipient, Addr::unchecked("payout0000"));
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistProfile { artist: String::from("artist0000") }).unwrap();
+        assert_eq!(from_json::<ArtistProfileResponse>(&res).unwrap(), ArtistProfileResponse {
+            artist: Addr::unchecked("artist0000"),
+            profile: ArtistProfile {
+                display_name: Some(String::from("Artist")),
+                payout_address: Some(Addr::unchecked("payout0000")),
+                submitted: 1,
+                curated: 1,
+                sold: 0,
+                total_proceeds: Uint128::zero(),
+            },
+        });
+
+        //Sell the first submission & the artist's
+        let mut env = mock_env();
+        for (token_id, bid) in [("1", 10_000_000u128), ("2", 20_000_000u128)] {
+            let _res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("bidder0000", &[coin(bid, "cdt")]),
+                ExecuteMsg::BidForNft { max_bid: None },
+            ).unwrap();
+            env.block.time = env.block.time.plus_seconds(86400);
+            let _res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("anyone0000", &[]),
+                ExecuteMsg::ConcludeAuction { },
+            ).unwrap();
+            let _res = reply(deps.as_mut(), env.clone(), Reply {
+                id: 2,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![Event::new("wasm").add_attribute("token_id", token_id)],
+                    data: None,
+                }),
+            }).unwrap();
+        }
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistProfile { artist: String::from("artist0000") }).unwrap();
+        let resp: ArtistProfileResponse = from_json(&res).unwrap();
+        assert_eq!(resp.profile.sold, 1);
+        assert_eq!(resp.profile.total_proceeds, Uint128::new(18_000_000));
+
+        //Update Artist Profile: Success, an empty payout address clears it & the display name is kept
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[]),
+            ExecuteMsg::UpdateArtistProfile { display_name: None, payout_address: Some(String::new()) },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistProfile { artist: String::from("artist0000") }).unwrap();
+        let resp: ArtistProfileResponse = from_json(&res).unwrap();
+        assert_eq!(resp.profile.display_name, Some(String::from("Artist")));
+        assert_eq!(resp.profile.payout_address, None);
+
+        //Leaderboard is ordered by total proceeds
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistLeaderboard { limit: None, start_after: None }).unwrap();
+        let resp: ArtistProfilesResponse = from_json(&res).unwrap();
+        assert_eq!(resp.profiles.iter().map(|profile| (profile.artist.to_string(), profile.profile.total_proceeds.u128())).collect::<Vec<_>>(), vec![
+            (String::from("artist0000"), 18_000_000),
+            (String::from("sender88"), 9_000_000),
+        ]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistLeaderboard { limit: None, start_after: Some(String::from("artist0000")) }).unwrap();
+        let resp: ArtistProfilesResponse = from_json(&res).unwrap();
+        assert_eq!(resp.profiles.len(), 1);
+        assert_eq!(resp.profiles[0].artist, Addr::unchecked("sender88"));
+
+        //Set Artist Discount: Error - owner only
+        let discount = ArtistDiscount {
+            min_sold: 1,
+            submission_cost_discount: Decimal::percent(50),
+            curation_threshold_discount: Decimal::percent(50),
+        };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("artist0000", &[]),
+            ExecuteMsg::SetArtistDiscount { discount: Some(discount.clone()) },
+        ).unwrap_err();
+        //Set Artist Discount: Error - more than 100%
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetArtistDiscount { discount: Some(ArtistDiscount { submission_cost_discount: Decimal::percent(101), ..discount.clone() }) },
+        ).unwrap_err();
+        //Set Artist Discount: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::SetArtistDiscount { discount: Some(discount) },
+        ).unwrap();
+
+        //Artists that sold pay the discounted submission cost, new artists don't
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("artist0001", &[coin(5_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0001")),
+                token_uri: String::from("ipfs://imageFolderCID/3.png"),
+                metadata: None,
+            },
+        ).unwrap_err();
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("artist0000", &[coin(5_000_000, "cdt")]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("proceed_recipient0001")),
+                token_uri: String::from("ipfs://imageFolderCID/3.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ArtistProfile { artist: String::from("artist0000") }).unwrap();
+        assert_eq!(from_json::<ArtistProfileResponse>(&res).unwrap().profile.submitted, 2);
+    }
+
+    #[test]
+    fn curator_stats(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Update Config: Error - curator reward percent over 100%
+        let mut update_config_msg = ExecuteMsg::UpdateConfig {
+            owner: None,
+            bid_denom: None,
+            minimum_outbid: None,
+            incentive_denom: None,
+            incentive_bid_percent: None,
+            mint_cost: None,
+            submission_cost: None,
+            submission_limit: None,
+            submission_vote_period: None,
+            curation_threshold: None,
+            auction_period: None,
+            asset_auction_period: None,
+            soft_close_period: None,
+            royalty_policy: None,
+            royalty_denom: None,
+            burn_strategy: None,
+            guardian: None,
+            no_bid_policy: None,
+            reserve_price: None,
+            mint_fee_source: None,
+            low_reserve_mints: None,
+            curator_reward_percent: Some(Decimal::percent(101)),
+            curator_hit_price: Some(15_000_000u128),
+        };
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            update_config_msg.clone(),
+        ).unwrap_err();
+        //Update Config: Success
+        if let ExecuteMsg::UpdateConfig { ref mut curator_reward_percent, .. } = update_config_msg {
+            *curator_reward_percent = None;
+        }
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            update_config_msg,
+        ).unwrap();
+
+        //Submit 2 NFTs & curate them
+        for token_uri in ["ipfs://imageFolderCID/2.png", "ipfs://imageFolderCID/3.png"] {
+            let _res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("artist0000", &[coin(10_000_000, "cdt")]),
+                ExecuteMsg::SubmitNft {
+                    proceed_recipient: Some(String::from("proceed_recipient0001")),
+                    token_uri: String::from(token_uri),
+                    metadata: None,
+                },
+            ).unwrap();
+        }
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0, 1] },
+        ).unwrap();
+
+        //Sell the first submission, then one above & one below the hit price
+        let mut env = mock_env();
+        for (token_id, bid) in [("1", 10_000_000u128), ("2", 20_000_000u128), ("3", 10_000_000u128)] {
+            let _res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("bidder0000", &[coin(bid, "cdt")]),
+                ExecuteMsg::BidForNft { max_bid: None },
+            ).unwrap();
+            env.block.time = env.block.time.plus_seconds(86400);
+            let _res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("anyone0000", &[]),
+                ExecuteMsg::ConcludeAuction { },
+            ).unwrap();
+            let _res = reply(deps.as_mut(), env.clone(), Reply {
+                id: 2,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![Event::new("wasm").add_attribute("token_id", token_id)],
+                    data: None,
+                }),
+            }).unwrap();
+        }
+
+        //The hit was cancelled out by the miss
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::CuratorStats { addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs") }).unwrap();
+        assert_eq!(from_json::<CuratorStatsResponse>(&res).unwrap(), CuratorStatsResponse {
+            addr: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            stats: CuratorStats {
+                score: 0,
+                hits: 1,
+                misses: 1,
+                pending_rewards: Uint128::zero(),
+                reward_index: Decimal::zero(),
+            },
+        });
+
+        //Claim Curator Rewards: Error - nothing to claim
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+            ExecuteMsg::ClaimCuratorRewards { },
+        ).unwrap_err();
+    }
+
+    #[test]
+    fn free_voters(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[coin(10_000, "ustars")]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //The instantiate free voter has 1 vote that doesn't expire
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::FreeVoters { limit: None, start_after: None }).unwrap();
+        assert_eq!(from_json::<FreeVotersResponse>(&res).unwrap().voters, vec![FreeVoterResponse {
+            addr: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            voter: FreeVoter { weight: 1, expiration: None },
+        }]);
+
+        let expiration = mock_env().block.time.seconds() + 86400;
+        //Add Free Voter: Error - owner only
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("partner_dao", &[]),
+            ExecuteMsg::AddFreeVoter { addr: String::from("partner_dao"), weight: 3, expiration: Some(expiration) },
+        ).unwrap_err();
+        //Add Free Voter: Error - no weight
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::AddFreeVoter { addr: String::from("partner_dao"), weight: 0, expiration: Some(expiration) },
+        ).unwrap_err();
+        //Add Free Voter: Error - expired
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::AddFreeVoter { addr: String::from("partner_dao"), weight: 3, expiration: Some(mock_env().block.time.seconds()) },
+        ).unwrap_err();
+        //Add Free Voter: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::AddFreeVoter { addr: String::from("partner_dao"), weight: 3, expiration: Some(expiration) },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::FreeVoters { limit: None, start_after: None }).unwrap();
+        assert_eq!(from_json::<FreeVotersResponse>(&res).unwrap().voters, vec![
+            FreeVoterResponse {
+                addr: Addr::unchecked("partner_dao"),
+                voter: FreeVoter { weight: 3, expiration: Some(expiration) },
+            },
+            FreeVoterResponse {
+                addr: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+                voter: FreeVoter { weight: 1, expiration: None },
+            },
+        ]);
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VotingPower { addr: String::from("partner_dao") }).unwrap();
+        assert_eq!(from_json::<VotingPowerResponse>(&res).unwrap().total, 3);
+
+        //Free voters submit without the submission cost
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("partner_dao", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("partner_dao")),
+                token_uri: String::from("ipfs://imageFolderCID/2.png"),
+                metadata: None,
+            },
+        ).unwrap();
+        //Curate with its weight
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("partner_dao", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+        assert_eq!(res.events[0].attributes[1].value, "3");
+
+        //Expired free voters are back to their NFTs
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::VotingPower { addr: String::from("partner_dao") }).unwrap();
+        assert_eq!(from_json::<VotingPowerResponse>(&res).unwrap().total, 0);
+        let _res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("partner_dao", &[]),
+            ExecuteMsg::SubmitNft {
+                proceed_recipient: Some(String::from("partner_dao")),
+                token_uri: String::from("ipfs://imageFolderCID/3.png"),
+                metadata: None,
+            },
+        ).unwrap_err();
+
+        //Remove Free Voter: Success
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::RemoveFreeVoter { addr: String::from("partner_dao") },
+        ).unwrap();
+        //Remove Free Voter: Error - not a free voter
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::RemoveFreeVoter { addr: String::from("partner_dao") },
+        ).unwrap_err();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::FreeVoters { limit: None, start_after: None }).unwrap();
+        assert_eq!(from_json::<FreeVotersResponse>(&res).unwrap().voters.len(), 1);
+    }
+
+    #[test]
+    fn delegation(){
+        let mut deps = mock_dependencies_with_collection();
+
+        let msg = InstantiateMsg {
+            sg721_code_id: None,
+            collection_params: None,
+            start_trading_time: None,
+            free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            sg721_addr: Some(String::from("some_sg721_address")),
+            minter_addr: Some(String::from("some_minter_address")),
+            base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("meme") }),
+            first_submission: SubmissionInfo {
+                submitter: Addr::unchecked(""),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from("ipfs://imageFolderCID/1.png"),
+            },
+            mint_cost: 1000u64,
+        };
+        //Instantiating contract
+        let v_info = mock_info("sender88", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), v_info, msg).unwrap();
+
+        //Submit 2 NFTs
+        for token_uri in ["ipfs://imageFolderCID/2.png", "ipfs://imageFolderCID/3.png"] {
+            let _res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs", &[]),
+                ExecuteMsg::SubmitNft {
+                    proceed_recipient: None,
+                    token_uri: String::from(token_uri),
+                    metadata: None,
+                },
+            ).unwrap();
+        }
+
+        //Curate: Error - the delegate has no votes of its own
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("delegate0000", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap_err();
+
+        //Delegate the holder's votes
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("holder0000", &[]),
+            ExecuteMsg::DelegateVotes { to: String::from("delegate0000") },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VotingPower { addr: String::from("delegate0000") }).unwrap();
+        assert_eq!(from_json::<VotingPowerResponse>(&res).unwrap(), VotingPowerResponse { votes: 0, delegated_votes: 1, total: 1 });
+
+        //Curate: the delegated vote reaches the threshold & queues the submission
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("delegate0000", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![0] },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionStatus { id: 0 }).unwrap();
+        assert_eq!(from_json::<SubmissionStatus>(&res).unwrap(), SubmissionStatus::Queued { position: 0 });
+
+        //Undelegate: the delegate's votes go with it
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("holder0000", &[]),
+            ExecuteMsg::Undelegate { },
+        ).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VotingPower { addr: String::from("delegate0000") }).unwrap();
+        assert_eq!(from_json::<VotingPowerResponse>(&res).unwrap(), VotingPowerResponse { votes: 0, delegated_votes: 0, total: 0 });
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("delegate0000", &[]),
+            ExecuteMsg::VoteToCurate { submission_ids: vec![1] },
+        ).unwrap_err();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SubmissionStatus { id: 1 }).unwrap();
+        assert_eq!(from_json::<SubmissionStatus>(&res).unwrap(), SubmissionStatus::Voting);
+    }
+
+    #[test]
+    fn migrate_legacy_state(){
+        let mut deps = mock_dependencies_with_collection();
+        let env = mock_env();
+
+        //v0.1.0 storage with a live auction, a pending auction, a bid asset auction & a submission
+        cw2::set_contract_version(deps.as_mut().storage, "pre_mint_auction", "0.1.0").unwrap();
+        let legacy_config = legacy::Config {
+            owner: Addr::unchecked("sender88"),
+            free_vote_addr: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            bid_denom: String::from("cdt"),
+            minimum_outbid: Decimal::percent(1),
+            incentive_denom: Some(String::from("meme")),
+            incentive_bid_percent: Decimal::percent(10),
+            current_submission_id: 4,
+            sg721_addr: String::from("some_sg721_address"),
+            minter_addr: String::from("some_minter_address"),
+            mint_cost: 1000,
+            submission_cost: 1_000_000,
+            submission_limit: 333,
+            submission_total: 1,
+            submission_vote_period: 7,
+            curation_threshold: Decimal::percent(11),
+            auction_period: 1,
+        };
+        legacy::CONFIG.save(deps.as_mut().storage, &legacy_config).unwrap();
+        let legacy_submission = |token_uri: &str| legacy::SubmissionItem {
+            submission: SubmissionInfo {
+                submitter: Addr::unchecked("artist0000"),
+                proceed_recipient: Addr::unchecked("proceed_recipient0000"),
+                token_uri: String::from(token_uri),
+            },
+            curators: vec![Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs")],
+            votes: 1,
+            submission_end_time: env.block.time.seconds() + 7 * 86400,
+        };
+        legacy::NFT_AUCTION.save(deps.as_mut().storage, &legacy::Auction {
+            submission_info: legacy_submission("ipfs://imageFolderCID/1.png"),
+            bids: vec![
+                Bid { bidder: Addr::unchecked("bidder0000"), amount: 1_000_000 },
+                Bid { bidder: Addr::unchecked("bidder0001"), amount: 2_000_000 },
+            ],
+            highest_bid: Bid { bidder: Addr::unchecked("bidder0001"), amount: 2_000_000 },
+            auction_end_time: env.block.time.seconds() + 86400,
+        }).unwrap();
+        legacy::PENDING_AUCTION.save(deps.as_mut().storage, &vec![legacy::Auction {
+            submission_info: legacy_submission("ipfs://imageFolderCID/2.png"),
+            bids: vec![],
+            highest_bid: Bid { bidder: Addr::unchecked(""), amount: 0 },
+            auction_end_time: 0,
+        }]).unwrap();
+        legacy::ASSET_AUCTION.save(deps.as_mut().storage, &legacy::BidAssetAuction {
+            auctioned_asset: coin(5_000_000, "cdt"),
+            highest_bid: Bid { bidder: env.contract.address.clone(), amount: 0 },
+        }).unwrap();
+        legacy::SUBMISSIONS.save(deps.as_mut().storage, 3, &legacy_submission("ipfs://imageFolderCID/3.png")).unwrap();
+        //The escrowed bid, the auctioned asset, 1_000_000 of submission costs & ustars for mints
+        deps.querier.update_balance(env.contract.address.clone(), vec![coin(8_000_000, "cdt"), coin(10_000, "ustars")]);
+
+        //Migrate: Success
+        let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        assert_eq!(res.attributes[2].value, "1");
+
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Config { }).unwrap();
+        let resp: Config = from_json(&res).unwrap();
+        assert_eq!(resp.bid_denom, AssetInfo::NativeToken { denom: String::from("cdt") });
+        assert_eq!(resp.incentive_denom, Some(AssetInfo::NativeToken { denom: String::from("meme") }));
+        assert_eq!(resp.current_submission_id, 4);
+
+        //The live auction's bids moved to the bid history
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::LiveNftAuction { }).unwrap();
+        let resp: LiveNftAuctionResponse = from_json(&res).unwrap();
+        let live_auction = resp.auction.unwrap();
+        assert_eq!(live_auction.bid_count, 2);
+        assert_eq!(live_auction.submission_info.submission.token_uri, String::from("ipfs://imageFolderCID/1.png"));
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::BidHistory { auction_id: live_auction.auction_id, limit: None, start_after: None }).unwrap();
+        let resp: BidHistoryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.bids.len(), 2);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::PendingAuctions { limit: None, start_after: None }).unwrap();
+        let resp: PendingAuctionResponse = from_json(&res).unwrap();
+        assert_eq!(resp.pending_auctions.len(), 1);
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AuctionState { }).unwrap();
+        assert_eq!(from_json::<AuctionState>(&res).unwrap(), AuctionState::Live);
+
+        //The bid asset auction ends with the live auction
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::LiveBidAssetAuction { }).unwrap();
+        let resp: BidAssetAuctionResponse = from_json(&res).unwrap();
+        let asset_auction = resp.auction.unwrap();
+        assert_eq!(asset_auction.auctioned_asset, vec![Asset { info: AssetInfo::NativeToken { denom: String::from("cdt") }, amount: Uint128::new(5_000_000) }]);
+        assert_eq!(asset_auction.auction_end_time, live_auction.auction_end_time);
+
+        //Ledgers are seeded from the balances
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Treasury { }).unwrap();
+        let resp: TreasuryResponse = from_json(&res).unwrap();
+        assert_eq!(resp.escrowed_bids, Uint128::new(2_000_000));
+        assert_eq!(resp.submission_fees, Uint128::new(1_000_000));
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::MintReserve { }).unwrap();
+        let resp: MintReserveResponse = from_json(&res).unwrap();
+        assert_eq!(resp.reserve, Uint128::new(10_000));
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::Paused { }).unwrap();
+        let resp: PausedResponse = from_json(&res).unwrap();
+        assert_eq!(resp.paused, vec![]);
+
+        //The legacy free_vote_addr is a free voter
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::FreeVoters { limit: None, start_after: None }).unwrap();
+        assert_eq!(from_json::<FreeVotersResponse>(&res).unwrap().voters, vec![FreeVoterResponse {
+            addr: Addr::unchecked("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
+            voter: FreeVoter { weight: 1, expiration: None },
+        }]);
+
+        //Submissions are indexed
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SubmissionsBySubmitter { addr: String::from("artist0000"), limit: None, start_after: None }).unwrap();
+        let resp: SubmissionsResponse = from_json(&res).unwrap();
+        assert_eq!(resp.submissions.len(), 1);
+
+        //Outbidding the migrated auction refunds the legacy highest bidder
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder0002", &[coin(3_000_000, "cdt")]),
+            ExecuteMsg::BidForNft { max_bid: None },
+        ).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("bidder0001"),
+            amount: vec![coin(2_000_000, "cdt")],
+        }))]);
+
+        //Migrating the current version leaves the state as is
+        let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        assert_eq!(res.attributes.len(), 2);
+
+        //Migrate: Error - another contract
+        cw2::set_contract_version(deps.as_mut().storage, "crates.io:other", "0.1.0").unwrap();
+        let _res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap_err();
+
+        //A ustars bid_denom's escrowed bid isn't seeded into the mint reserve
+        let mut deps = mock_dependencies_with_collection();
+        cw2::set_contract_version(deps.as_mut().storage, "pre_mint_auction", "0.1.0").unwrap();
+        legacy::CONFIG.save(deps.as_mut().storage, &legacy::Config {
+            bid_denom: String::from("ustars"),
+            ..legacy_config
+        }).unwrap();
+        legacy::NFT_AUCTION.save(deps.as_mut().storage, &legacy::Auction {
+            submission_info: legacy_submission("ipfs://imageFolderCID/1.png"),
+            bids: vec![Bid { bidder: Addr::unchecked("bidder0000"), amount: 2_000 }],
+            highest_bid: Bid { bidder: Addr::unchecked("bidder0000"), amount: 2_000 },
+            auction_end_time: env.block.time.seconds() + 86400,
+        }).unwrap();
+        legacy::PENDING_AUCTION.save(deps.as_mut().storage, &vec![]).unwrap();
+        deps.querier.update_balance(env.contract.address.clone(), vec![coin(12_000, "ustars")]);
+        let _res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        let res = query(deps.as_ref(), env, QueryMsg::MintReserve { }).unwrap();
+        let resp: MintReserveResponse = from_json(&res).unwrap();
+        assert_eq!(resp.reserve, Uint128::new(10_000));
+    }
+}
@@ -3,16 +3,17 @@
 mod tests {
 
     use cosmwasm_std::{ to_json_binary,
-        coin, Addr, Binary, Empty, Response, StdResult, Uint128, Decimal,
+        coin, Addr, Binary, Empty, Response, StdError, StdResult, Timestamp, Uint128, Decimal,
     };
-    use crate::msgs::TokensResponse;
+    use cw_storage_plus::Item;
+    use crate::msgs::{BaseMinterConfig, BaseMinterConfigResponse, CollectionInfo, MinterResponse, NumTokensResponse, RoyaltyInfoResponse, TokensResponse, UpdateCollectionInfoMsg};
     use cw_multi_test::{App, AppBuilder, BankKeeper, Contract, ContractWrapper, Executor};
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
 
     use crate::state::SubmissionInfo;
     use crate::testing::helpers::AuctionContract;
-    use crate::msgs::InstantiateMsg;
+    use crate::msgs::{AssetInfo, InstantiateMsg};
 
     const USER: &str = "user";
     const ADMIN: &str = "admin";
@@ -29,15 +30,21 @@ mod tests {
 
     
     //Mock sg721 Contract
-    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub enum sg721_MockExecuteMsg {
         TransferNft {
             recipient: String,
             token_id: String,
-        }
+        },
+        UpdateCollectionInfo {
+            collection_info: UpdateCollectionInfoMsg<RoyaltyInfoResponse>,
+        },
+        FreezeCollectionInfo,
     }
 
+    const FROZEN: Item<bool> = Item::new("frozen");
+
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub struct sg721_MockInstantiateMsg {}
@@ -50,16 +57,27 @@ mod tests {
             start_after: Option<String>,
             limit: Option<u32>,
         },
-        AllTokens {
-            start_after: Option<String>,
-            limit: Option<u32>,
-        }
+        NumTokens {},
+        Minter {},
+        CollectionInfo {},
     }
     
     pub fn sg721_contract() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new(
             |deps, _, info, msg: sg721_MockExecuteMsg| -> StdResult<Response> {
-                Ok(Response::default())
+                match msg {
+                    sg721_MockExecuteMsg::UpdateCollectionInfo { collection_info } => {
+                        if FROZEN.may_load(deps.storage)?.unwrap_or(false) {
+                            return Err(StdError::generic_err("Collection info frozen"));
+                        }
+                        Ok(Response::default())
+                    },
+                    sg721_MockExecuteMsg::FreezeCollectionInfo => {
+                        FROZEN.save(deps.storage, &true)?;
+                        Ok(Response::default())
+                    },
+                    _ => Ok(Response::default()),
+                }
             },
             |_, _, _, _: sg721_MockInstantiateMsg| -> StdResult<Response> {
                 Ok(Response::default())
@@ -88,33 +106,27 @@ mod tests {
                             })?)
                         }
                     },
-                    sg721_MockQueryMsg::AllTokens { start_after, limit } => {
-                        Ok(to_json_binary(&TokensResponse {
-                            tokens: vec![
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                                String::from("1"),
-                                String::from("2"),
-                                String::from("3"),
-                            ],
+                    sg721_MockQueryMsg::NumTokens {  } => {
+                        Ok(to_json_binary(&NumTokensResponse { count: 21 })?)
+                    },
+                    //contract0 = Mint contract
+                    sg721_MockQueryMsg::Minter {  } => {
+                        Ok(to_json_binary(&MinterResponse {
+                            minter: Some(String::from("contract0")),
+                        })?)
+                    },
+                    //contract2 = Auction contract
+                    sg721_MockQueryMsg::CollectionInfo {  } => {
+                        Ok(to_json_binary(&CollectionInfo::<RoyaltyInfoResponse> {
+                            creator: String::from("contract2"),
+                            description: String::from("description"),
+                            image: String::from("ipfs://imageFolderCID/collection.png"),
+                            external_link: None,
+                            explicit_content: None,
+                            start_trading_time: None,
+                            royalty_info: None,
                         })?)
-                    }
+                    },
                 }
             },
         );
@@ -127,7 +139,8 @@ mod tests {
     pub enum Mint_MockExecuteMsg {
         Mint {
             token_uri: Option<String>,
-        }
+        },
+        UpdateStartTradingTime(Option<Timestamp>),
     }
 
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
@@ -137,22 +150,39 @@ mod tests {
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub enum Mint_MockQueryMsg {
+        Config {},
     }
-    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
-    #[serde(rename_all = "snake_case")]
-    pub struct Mock_Response { }
 
 
     pub fn mint_contract() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new(
-            |deps, _, info, msg: Mint_MockExecuteMsg| -> StdResult<Response> {
-                Ok(Response::default().add_attribute("token_id", "1"))
+            |deps, env, info, msg: Mint_MockExecuteMsg| -> StdResult<Response> {
+                match msg {
+                    Mint_MockExecuteMsg::Mint { token_uri } => Ok(Response::default().add_attribute("token_id", "1")),
+                    Mint_MockExecuteMsg::UpdateStartTradingTime(start_trading_time) => {
+                        if let Some(start_trading_time) = start_trading_time {
+                            if start_trading_time < env.block.time {
+                                return Err(StdError::generic_err("Start trading time can't be in the past"));
+                            }
+                        }
+                        Ok(Response::default())
+                    },
+                }
             },
             |_, _, _, _: Mint_MockInstantiateMsg| -> StdResult<Response> {
                 Ok(Response::default())
             },
+            //contract1 = sg721 contract
             |_, _, msg: Mint_MockQueryMsg| -> StdResult<Binary> {
-                Ok(to_json_binary(&Mock_Response {})?)
+                Ok(to_json_binary(&BaseMinterConfigResponse {
+                    collection_address: String::from("contract1"),
+                    config: BaseMinterConfig {
+                        factory: Addr::unchecked("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
+                        collection_code_id: 180,
+                        mint_price: coin(0, "ustars"),
+                        extension: Empty {},
+                    },
+                })?)
             },
         );
         Box::new(contract)
@@ -189,7 +219,7 @@ mod tests {
             bank.init_balance(
                 storage,
                 &Addr::unchecked(USER),
-                vec![coin(100_000_000_000_000, "cdt"), coin(30_000_000_000_000, "mbrn")],
+                vec![coin(100_000_000_000_000, "cdt"), coin(30_000_000_000_000, "mbrn"), coin(1_000_000, "ustars")],
             )
             .unwrap();
 
@@ -237,12 +267,13 @@ mod tests {
         let msg = InstantiateMsg {
             sg721_code_id: None,
             collection_params: None,
+            start_trading_time: None,
             free_vote_addr: String::from("stars1988s5h45qwkaqch8km4ceagw2e08vdw2mu2mgs"),
             sg721_addr: Some(sg721_contract_addr.to_string()),
             minter_addr: Some(mint_contract_addr.to_string()),
             base_factory_address: String::from("stars1a45hcxty3spnmm2f0papl8v4dk5ew29s4syhn4efte8u5haex99qlkrtnx"),
-            bid_denom: String::from("cdt"),
-            incentive_denom: Some(String::from("mbrn")),
+            bid_denom: AssetInfo::NativeToken { denom: String::from("cdt") },
+            incentive_denom: Some(AssetInfo::NativeToken { denom: String::from("mbrn") }),
             first_submission: SubmissionInfo {
                 submitter: Addr::unchecked(""),
                 proceed_recipient: Addr::unchecked("proceed_recipient0000"),
@@ -269,7 +300,7 @@ mod tests {
 
     mod auction {
 
-        use crate::{msgs::{ExecuteMsg, QueryMsg}, state::{Bid, BidAssetAuction}};
+        use crate::{msgs::{Asset, BidAssetAuctionResponse, BurnedResponse, CuratorStatsResponse, DelegationResponse, ExecuteMsg, QueryMsg, SubmissionsResponse}, state::{Bid, BidAssetAuction}};
 
         use super::*;
 
@@ -277,18 +308,59 @@ mod tests {
         fn mock_usage() {
             let (mut app, auction_contract) = proper_instantiate();
 
+            //Fund Mint Reserve: Error with non-ustars funds
+            let fund_msg = ExecuteMsg::FundMintReserve { };
+            let cosmos_msg = auction_contract.call(fund_msg, vec![coin(10_000, "cdt")]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Fund Mint Reserve: Success
+            let fund_msg = ExecuteMsg::FundMintReserve { };
+            let cosmos_msg = auction_contract.call(fund_msg, vec![coin(10_000, "ustars")]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+
+            //Share half of winning bid asset auction bids with curators
+            let update_config_msg = ExecuteMsg::UpdateConfig {
+                owner: None,
+                bid_denom: None,
+                minimum_outbid: None,
+                incentive_denom: None,
+                incentive_bid_percent: None,
+                mint_cost: None,
+                submission_cost: None,
+                submission_limit: None,
+                submission_vote_period: None,
+                curation_threshold: None,
+                auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: None,
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: Some(Decimal::percent(50)),
+                curator_hit_price: None,
+            };
+            let cosmos_msg = auction_contract.call(update_config_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
             //Submit NFT: Error without submission funds
             let submit_msg = ExecuteMsg::SubmitNft {
-                proceed_recipient: String::from("proceed_recipient0000"),
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
                 token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
             };
             let cosmos_msg = auction_contract.call(submit_msg, vec![]).unwrap();
             app.execute(Addr::unchecked("not_a_holder"), cosmos_msg).unwrap_err();
 
             //Submit NFT: Success
             let submit_msg = ExecuteMsg::SubmitNft {
-                proceed_recipient: String::from("proceed_recipient0000"),
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
                 token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
             };
             let cosmos_msg = auction_contract.call(submit_msg, vec![coin(10_000_000, "cdt")]).unwrap();
             app.execute(Addr::unchecked("not_a_holder"), cosmos_msg).unwrap();
@@ -308,17 +380,17 @@ mod tests {
             app.execute(Addr::unchecked("three_votes"), cosmos_msg).unwrap();
 
             //Bid for NFT current live auction
-            let bid_msg = ExecuteMsg::BidForNft { };
+            let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
             let cosmos_msg = auction_contract.call(bid_msg, vec![coin(1_000_000, "cdt")]).unwrap();
-            app.execute(Addr::unchecked("nft_bidder"), cosmos_msg).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
             
             //Bid for NFT current live auction: Error bid too low
-            let bid_msg = ExecuteMsg::BidForNft { };
+            let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
             let cosmos_msg = auction_contract.call(bid_msg, vec![coin(1_000_100, "cdt")]).unwrap();
             app.execute(Addr::unchecked("nft_bidder"), cosmos_msg).unwrap_err();
 
             //Bid for NFT current live auction: Outbid returns funds
-            let bid_msg = ExecuteMsg::BidForNft { };
+            let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
             let cosmos_msg = auction_contract.call(bid_msg, vec![coin(10_000_000, "cdt")]).unwrap();
             app.execute(Addr::unchecked("nft_bidder"), cosmos_msg).unwrap();
 
@@ -351,7 +423,7 @@ mod tests {
 
 
             //Bid for NFT current live auction
-            let bid_msg = ExecuteMsg::BidForNft { };
+            let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
             let cosmos_msg = auction_contract.call(bid_msg, vec![coin(10_000_000, "cdt")]).unwrap();
             app.execute(Addr::unchecked("nft_bidder"), cosmos_msg).unwrap();
 
@@ -365,8 +437,8 @@ mod tests {
             let query_msg = QueryMsg::LiveBidAssetAuction { };
             let res: BidAssetAuction = app
                 .wrap()
-                .query_wasm_smart(auction_contract.addr(), &query_msg.clone())
-                .unwrap();
+                .query_wasm_smart::<BidAssetAuctionResponse>(auction_contract.addr(), &query_msg.clone())
+                .unwrap().auction.unwrap();
             assert_eq!(res.highest_bid, Bid {
                 bidder: Addr::unchecked("asset_bidder"),
                 amount: 10000000u128
@@ -377,16 +449,29 @@ mod tests {
             let cosmos_msg = auction_contract.call(conclude_msg, vec![]).unwrap();
             app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
                        
-            //Check to see that the asset_bidder got the NFT's bid amount & the submission cost from the non_holder
+            //Check to see that the asset_bidder got the NFT's bid amount, the non_holder's submission cost is escrowed until it resolves
             assert_eq!(
                 app.wrap().query_all_balances(Addr::unchecked("asset_bidder")).unwrap(),
                 vec![
-                    coin(11_000_000, "cdt"), //new from winning the auction bid -> 1 from the first NFT bid and 10 from the submission cost
+                    coin(1_000_000, "cdt"), //new from winning the auction bid -> 1 from the first NFT bid
                     coin(10000000, "mbrn") //old
                     ]
             );
             //Check that the curators have been rewarded with MBRN that was sent in the first bid
             //Both curators get the same amount
+            let res: CuratorStatsResponse = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::CuratorStats { addr: String::from("three_votes") })
+                .unwrap();
+            assert_eq!(res.stats.score, 1);
+            assert_eq!(res.stats.pending_rewards, Uint128::new(2_500_000));
+            for curator in ["three_votes", "one_vote"] {
+                let cosmos_msg = auction_contract.call(ExecuteMsg::ClaimCuratorRewards { }, vec![]).unwrap();
+                app.execute(Addr::unchecked(curator), cosmos_msg).unwrap();
+            }
+            //Nothing left to claim
+            let cosmos_msg = auction_contract.call(ExecuteMsg::ClaimCuratorRewards { }, vec![]).unwrap();
+            app.execute(Addr::unchecked("one_vote"), cosmos_msg).unwrap_err();
             assert_eq!(
                 app.wrap().query_all_balances(Addr::unchecked("three_votes")).unwrap(),
                 vec![
@@ -413,7 +498,6 @@ mod tests {
             //Update incentive_distribution_amount to 0
             let update_config_msg = ExecuteMsg::UpdateConfig {
                 owner: None,
-                free_vote_addr: None,
                 bid_denom: None,
                 minimum_outbid: None,
                 incentive_denom: None,
@@ -425,6 +509,18 @@ mod tests {
                 submission_vote_period: None,
                 curation_threshold: None,
                 auction_period: None,
+                asset_auction_period: None,
+                soft_close_period: None,
+                royalty_policy: None,
+                royalty_denom: None,
+                burn_strategy: None,
+                guardian: None,
+                no_bid_policy: None,
+                reserve_price: None,
+                mint_fee_source: None,
+                low_reserve_mints: None,
+                curator_reward_percent: None,
+                curator_hit_price: None,
             };
             let cosmos_msg = auction_contract.call(update_config_msg, vec![]).unwrap();
             app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
@@ -442,8 +538,9 @@ mod tests {
             
             //Submit NFT
             let submit_msg = ExecuteMsg::SubmitNft {
-                proceed_recipient: String::from("proceed_recipient0000"),
+                proceed_recipient: Some(String::from("proceed_recipient0000")),
                 token_uri: String::from("ipfs://imageFolderCID/submission1.png"),
+                metadata: None,
             };
             let cosmos_msg = auction_contract.call(submit_msg, vec![]).unwrap();
             app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
@@ -456,7 +553,7 @@ mod tests {
             app.execute(Addr::unchecked("three_votes"), cosmos_msg).unwrap();
 
             //Bid for NFT current live auction
-            let bid_msg = ExecuteMsg::BidForNft { };
+            let bid_msg = ExecuteMsg::BidForNft { max_bid: None };
             let cosmos_msg = auction_contract.call(bid_msg, vec![coin(10_000_000, "cdt")]).unwrap();
             app.execute(Addr::unchecked("nft_bidder"), cosmos_msg).unwrap();
 
@@ -470,19 +567,20 @@ mod tests {
             let cosmos_msg = auction_contract.call(conclude_msg, vec![]).unwrap();
             app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
 
-            //Check to see that the burn address got the last bid asset bid
-            assert_eq!(
-                app.wrap().query_all_balances(Addr::unchecked("stars1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq8lhzvv")).unwrap(),
-                vec![
-                    coin(10_000_000, "mbrn")
-                    ]
-            );
+            //Check to see that the last bid asset bid was burned, minus the curator share
+            let res: BurnedResponse = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::Burned { })
+                .unwrap();
+            assert_eq!(res.burned, vec![
+                Asset { info: AssetInfo::NativeToken { denom: String::from("mbrn") }, amount: Uint128::new(10_000_000) },
+            ]);
             //Query Bid Asset Auction for default bid
             let query_msg = QueryMsg::LiveBidAssetAuction { };
             let res: BidAssetAuction = app
                 .wrap()
-                .query_wasm_smart(auction_contract.addr(), &query_msg.clone())
-                .unwrap();
+                .query_wasm_smart::<BidAssetAuctionResponse>(auction_contract.addr(), &query_msg.clone())
+                .unwrap().auction.unwrap();
             assert_eq!(res.highest_bid, Bid {
                 bidder: Addr::unchecked("contract2"),
                 amount: 0
@@ -490,5 +588,126 @@ mod tests {
             
         }
 
+        #[test]
+        fn delegated_votes() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Submit 2 NFTs
+            for token_uri in ["ipfs://imageFolderCID/submission1.png", "ipfs://imageFolderCID/submission2.png"] {
+                let submit_msg = ExecuteMsg::SubmitNft {
+                    proceed_recipient: Some(String::from("proceed_recipient0000")),
+                    token_uri: String::from(token_uri),
+                    metadata: None,
+                };
+                let cosmos_msg = auction_contract.call(submit_msg, vec![coin(10_000_000, "cdt")]).unwrap();
+                app.execute(Addr::unchecked(USER), cosmos_msg).unwrap();
+            }
+
+            //Delegate Votes: Error - to self
+            let delegate_msg = ExecuteMsg::DelegateVotes { to: String::from("delegator") };
+            let cosmos_msg = auction_contract.call(delegate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap_err();
+
+            //Delegate Votes: Success
+            let delegate_msg = ExecuteMsg::DelegateVotes { to: String::from("not_a_holder") };
+            let cosmos_msg = auction_contract.call(delegate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap();
+            let res: DelegationResponse = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::Delegation { addr: String::from("not_a_holder") })
+                .unwrap();
+            assert_eq!(res.delegators, vec![Addr::unchecked("delegator")]);
+
+            //Delegator votes directly on the 2nd submission
+            let curate_msg = ExecuteMsg::VoteToCurate { submission_ids: vec![1] };
+            let cosmos_msg = auction_contract.call(curate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap();
+
+            //Delegate votes with the delegator's NFT, which isn't counted twice on the 2nd submission
+            let curate_msg = ExecuteMsg::VoteToCurate { submission_ids: vec![0, 1] };
+            let cosmos_msg = auction_contract.call(curate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("not_a_holder"), cosmos_msg).unwrap();
+
+            //Delegator's direct vote on the 1st submission isn't counted twice either
+            let curate_msg = ExecuteMsg::VoteToCurate { submission_ids: vec![0] };
+            let cosmos_msg = auction_contract.call(curate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap();
+
+            let res: SubmissionsResponse = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::Submissions { submission_id: None, limit: None, start_after: None })
+                .unwrap();
+            assert_eq!(res.submissions.iter().map(|submission| submission.votes).collect::<Vec<u64>>(), vec![1, 1]);
+            assert_eq!(res.submissions[0].delegated_voters, vec![Addr::unchecked("delegator")]);
+            let res: bool = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::HasVoted { submission_id: 0, addr: String::from("delegator") })
+                .unwrap();
+            assert!(res);
+
+            //Undelegate: Success
+            let cosmos_msg = auction_contract.call(ExecuteMsg::Undelegate { }, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap();
+            //Undelegate: Error - no delegation
+            let cosmos_msg = auction_contract.call(ExecuteMsg::Undelegate { }, vec![]).unwrap();
+            app.execute(Addr::unchecked("delegator"), cosmos_msg).unwrap_err();
+            let res: DelegationResponse = app
+                .wrap()
+                .query_wasm_smart(auction_contract.addr(), &QueryMsg::Delegation { addr: String::from("delegator") })
+                .unwrap();
+            assert_eq!(res.delegate, None);
+
+            //Without delegators the delegate has no votes
+            let curate_msg = ExecuteMsg::VoteToCurate { submission_ids: vec![0] };
+            let cosmos_msg = auction_contract.call(curate_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked("not_a_holder"), cosmos_msg).unwrap_err();
+        }
+
+        #[test]
+        fn collection_admin() {
+            let (mut app, auction_contract) = proper_instantiate();
+
+            //Update Start Trading Time: Error - owner only
+            let update_msg = ExecuteMsg::UpdateStartTradingTime { start_trading_time: Some(app.block_info().time.plus_seconds(86400)) };
+            let cosmos_msg = auction_contract.call(update_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Update Start Trading Time: Error - in the past
+            let update_msg = ExecuteMsg::UpdateStartTradingTime { start_trading_time: Some(app.block_info().time.minus_seconds(1)) };
+            let cosmos_msg = auction_contract.call(update_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+
+            //Update Start Trading Time: Success
+            let update_msg = ExecuteMsg::UpdateStartTradingTime { start_trading_time: Some(app.block_info().time.plus_seconds(86400)) };
+            let cosmos_msg = auction_contract.call(update_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //Update Collection Info: Error - owner only
+            let update_msg = ExecuteMsg::UpdateCollectionInfo {
+                description: Some(String::from("new description")),
+                image: Some(String::from("ipfs://imageFolderCID/new_image.png")),
+                external_link: None,
+                explicit_content: None,
+            };
+            let cosmos_msg = auction_contract.call(update_msg.clone(), vec![]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Update Collection Info: Success
+            let cosmos_msg = auction_contract.call(update_msg.clone(), vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //Freeze Collection Info: Error - owner only
+            let cosmos_msg = auction_contract.call(ExecuteMsg::FreezeCollectionInfo { }, vec![]).unwrap();
+            app.execute(Addr::unchecked(USER), cosmos_msg).unwrap_err();
+
+            //Freeze Collection Info: Success
+            let cosmos_msg = auction_contract.call(ExecuteMsg::FreezeCollectionInfo { }, vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap();
+
+            //Update Collection Info: Error - the sg721 rejects updates once frozen
+            let cosmos_msg = auction_contract.call(update_msg, vec![]).unwrap();
+            app.execute(Addr::unchecked(ADMIN), cosmos_msg).unwrap_err();
+        }
+
     }
 }
@@ -1,12 +1,12 @@
 use cosmwasm_std::{
-    entry_point, has_coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, 
-    QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery, attr
+    entry_point, has_coins, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, 
+    Order, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery, attr
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 
-use crate::msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Config};
+use crate::msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Config, BurnStrategy, BurnedResponse};
 use crate::reply::handle_balancer_reply;
-use crate::state::{CONFIG, OWNERSHIP_TRANSFER};
+use crate::state::{legacy, BURNED, CONFIG, OWNERSHIP_TRANSFER};
 use crate::error::ContractError;
 
 use osmosis_std::types::osmosis::gamm::poolmodels::balancer::v1beta1::MsgCreateBalancerPool;
@@ -29,9 +29,12 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let burn_strategy = validate_burn_strategy(deps.as_ref(), msg.burn_strategy.unwrap_or(BurnStrategy::Burn))?;
+
     let config = Config {
         owner: info.sender.clone(),
         paired_asset: msg.paired_asset,
+        burn_strategy,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -52,7 +55,7 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::LP {} => LP(deps, env, info),
-        ExecuteMsg::UpdateConfig { paired_asset } => update_config(deps, info, paired_asset),
+        ExecuteMsg::UpdateConfig { paired_asset, burn_strategy } => update_config(deps, info, paired_asset, burn_strategy),
         }
 }
 
@@ -60,6 +63,7 @@ fn update_config(
     deps: DepsMut,
     info: MessageInfo,
     paired_asset: Option<String>,
+    burn_strategy: Option<BurnStrategy>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -70,6 +74,9 @@ fn update_config(
     if let Some(paired_asset) = paired_asset {
         config.paired_asset = paired_asset;
     }
+    if let Some(burn_strategy) = burn_strategy {
+        config.burn_strategy = validate_burn_strategy(deps.as_ref(), burn_strategy)?;
+    }
 
     CONFIG.save(deps.storage, &config)?;
 
@@ -79,6 +86,17 @@ fn update_config(
     )
 }
 
+/// Validate the sink address
+fn validate_burn_strategy(
+    deps: Deps,
+    burn_strategy: BurnStrategy,
+) -> StdResult<BurnStrategy> {
+    Ok(match burn_strategy {
+        BurnStrategy::Sink { addr } => BurnStrategy::Sink { addr: deps.api.addr_validate(&addr)?.to_string() },
+        burn_strategy => burn_strategy,
+    })
+}
+
 fn LP(
     deps: DepsMut,
     env: Env,
@@ -148,10 +166,55 @@ fn LP(
         .add_attribute("method", "create_LP")
     )
 }
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Burned {} => to_json_binary(&get_burned(deps)?),
+    }
+}
+
+fn get_burned(
+    deps: Deps,
+) -> StdResult<BurnedResponse> {
+    let burned: Vec<Coin> = BURNED
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(BurnedResponse { burned })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     match msg.id {
         BALANCER_POOL_REPLY_ID => handle_balancer_reply(deps, env, msg),
         id => Err(StdError::generic_err(format!("invalid reply id: {}", id))),
     }
-}
\ No newline at end of file
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version = get_contract_version(deps.storage)?;
+    if version.contract != CONTRACT_NAME {
+        return Err(ContractError::CustomError { val: format!("Can't migrate from contract {}", version.contract) });
+    }
+
+    //v0.1.0 configs burn with BankMsg::Burn
+    if version.version == legacy::CONTRACT_VERSION {
+        let legacy_config = legacy::CONFIG.load(deps.storage)?;
+        CONFIG.save(deps.storage, &Config {
+            owner: legacy_config.owner,
+            paired_asset: legacy_config.paired_asset,
+            burn_strategy: BurnStrategy::Burn,
+        })?;
+    } else if version.version != CONTRACT_VERSION {
+        return Err(ContractError::CustomError { val: format!("Can't migrate from version {}", version.version) });
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", version.version)
+    )
+}
@@ -7,3 +7,6 @@ pub mod error;
 pub mod state;
 pub mod msgs;
 pub mod reply;
+
+#[cfg(test)]
+pub mod testing;
@@ -1,10 +1,12 @@
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 use cosmwasm_schema::cw_serde;
 
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub paired_asset: String,
+    /// Defaults to Burn
+    pub burn_strategy: Option<BurnStrategy>,
 }
 
 #[cw_serde]
@@ -12,6 +14,7 @@ pub enum ExecuteMsg {
     LP { },
     UpdateConfig {
         paired_asset: Option<String>,
+        burn_strategy: Option<BurnStrategy>,
     },
 }
 
@@ -19,6 +22,8 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     /// Return contract config
     Config {},
+    /// Return total burned per denom
+    Burned {},
 }
 
 #[cw_serde]
@@ -27,6 +32,23 @@ pub struct Config {
     pub owner: Addr,
     /// Asset to pair with OSMO
     pub paired_asset: String,
+    /// How leftover assets & LP shares are burned
+    pub burn_strategy: BurnStrategy,
+}
+
+#[cw_serde]
+pub enum BurnStrategy {
+    /// BankMsg::Burn
+    Burn,
+    /// TokenFactory MsgBurn for the contract's factory denoms, BankMsg::Burn for the rest
+    TokenFactory,
+    /// Send to a sink address
+    Sink { addr: String },
+}
+
+#[cw_serde]
+pub struct BurnedResponse {
+    pub burned: Vec<Coin>,
 }
 
 #[cw_serde]
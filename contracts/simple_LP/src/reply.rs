@@ -3,13 +3,16 @@ use std::str::FromStr;
 
 use cosmwasm_std::{
     to_binary, Decimal, DepsMut, Env, WasmMsg, WasmQuery, attr,
-    Response, StdResult, Uint128, Reply, StdError, CosmosMsg, SubMsg, coins, QueryRequest, BankMsg,
+    Response, StdResult, Uint128, Reply, StdError, CosmosMsg, SubMsg, coins, QueryRequest, BankMsg, Coin,
 };
 
 use osmosis_std::types::osmosis::gamm::poolmodels::balancer::v1beta1::MsgCreateBalancerPoolResponse;
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
 use crate::error::ContractError;
+use crate::msgs::BurnStrategy;
+use crate::state::{BURNED, CONFIG};
 
-/// Send all contract assets to the burn address
+/// Burn all contract assets using the config's burn strategy
 pub fn handle_balancer_reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response>{
     match msg.clone().result.into_result() {
         Ok(result) => {
@@ -27,14 +30,49 @@ pub fn handle_balancer_reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<R
 
         //Query all assets in the contract
         let assets = deps.querier.query_all_balances(&env.contract.address)?;
-        //Send all assets to the burn address
-        let msg = BankMsg::Send {
-            to_address: String::from("osmo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqmcn030"),
-            amount: assets.clone(),
-        };
+        //Add to the burned totals
+        for asset in assets.clone() {
+            BURNED.update(deps.storage, asset.denom.clone(), |burned| -> StdResult<Uint128> {
+                Ok(burned.unwrap_or_default() + asset.amount)
+            })?;
+        }
+        
+        //Burn all assets
+        let config = CONFIG.load(deps.storage)?;
+        let mut msgs: Vec<CosmosMsg> = vec![];
+        if !assets.is_empty() {
+            match config.burn_strategy {
+                BurnStrategy::Burn => msgs.push(CosmosMsg::Bank(BankMsg::Burn {
+                    amount: assets.clone(),
+                })),
+                BurnStrategy::TokenFactory => {
+                    //MsgBurn only works for denoms the contract is the admin of, the rest use BankMsg::Burn
+                    let factory_prefix = format!("factory/{}/", env.contract.address);
+                    let (factory_assets, bank_assets): (Vec<Coin>, Vec<Coin>) = assets.clone()
+                        .into_iter()
+                        .partition(|asset| asset.denom.starts_with(&factory_prefix));
+                    for asset in factory_assets {
+                        msgs.push(MsgBurn {
+                            sender: env.contract.address.to_string(),
+                            amount: Some(osmosis_std::types::cosmos::base::v1beta1::Coin { denom: asset.denom, amount: asset.amount.to_string() }),
+                            burn_from_address: env.contract.address.to_string(),
+                        }.into());
+                    }
+                    if !bank_assets.is_empty() {
+                        msgs.push(CosmosMsg::Bank(BankMsg::Burn {
+                            amount: bank_assets,
+                        }));
+                    }
+                },
+                BurnStrategy::Sink { addr } => msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: addr,
+                    amount: assets.clone(),
+                })),
+            };
+        }
 
         Ok(Response::new()
-            .add_message(CosmosMsg::Bank(msg))
+            .add_messages(msgs)
             .add_attributes(attrs)
             .add_attribute("assets_burnt", format!("{:?}", assets))
         )
@@ -1,8 +1,27 @@
 use cosmwasm_schema::cw_serde;
 use cw_storage_plus::{Item, Map};
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Uint128};
 
 use crate::msgs::Config;
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const OWNERSHIP_TRANSFER: Item<Addr> = Item::new("ownership_transfer");
\ No newline at end of file
+pub const OWNERSHIP_TRANSFER: Item<Addr> = Item::new("ownership_transfer");
+//Total burned per denom
+pub const BURNED: Map<String, Uint128> = Map::new("burned");
+
+/// v0.1.0 storage, read once by migrate
+pub mod legacy {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Item;
+
+    pub const CONTRACT_VERSION: &str = "0.1.0";
+
+    #[cw_serde]
+    pub struct Config {
+        pub owner: Addr,
+        pub paired_asset: String,
+    }
+
+    pub const CONFIG: Item<Config> = Item::new("config");
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use crate::contracts::{query, instantiate, execute, migrate, reply};
+    use crate::msgs::{BurnStrategy, BurnedResponse, Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+    use crate::state::legacy;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_dependencies_with_balance, mock_env, mock_info};
+    use cosmwasm_std::{coin, from_json, Addr, BankMsg, CosmosMsg, Reply, SubMsg, SubMsgResponse, SubMsgResult};
+    use osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgBurn;
+
+    fn balancer_reply() -> Reply {
+        Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn burn_strategies(){
+        let factory_denom = format!("factory/{}/brane", mock_env().contract.address);
+        let mut deps = mock_dependencies_with_balance(&[coin(50, factory_denom.clone()), coin(100, "uosmo")]);
+
+        //Instantiate: Error - invalid sink address
+        let _res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            InstantiateMsg { paired_asset: String::from("uatom"), burn_strategy: Some(BurnStrategy::Sink { addr: String::from("SINK0000") }) },
+        ).unwrap_err();
+
+        //Instantiate: defaults to Burn
+        let _res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            InstantiateMsg { paired_asset: String::from("uatom"), burn_strategy: None },
+        ).unwrap();
+
+        //Burn: all assets use BankMsg::Burn
+        let res = reply(deps.as_mut(), mock_env(), balancer_reply()).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(50, factory_denom.clone()), coin(100, "uosmo")],
+        }))]);
+
+        //TokenFactory: the contract's factory denoms use MsgBurn
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig { paired_asset: None, burn_strategy: Some(BurnStrategy::TokenFactory) },
+        ).unwrap();
+        let res = reply(deps.as_mut(), mock_env(), balancer_reply()).unwrap();
+        let factory_burn: CosmosMsg = MsgBurn {
+            sender: mock_env().contract.address.to_string(),
+            amount: Some(osmosis_std::types::cosmos::base::v1beta1::Coin { denom: factory_denom.clone(), amount: String::from("50") }),
+            burn_from_address: mock_env().contract.address.to_string(),
+        }.into();
+        assert_eq!(res.messages, vec![
+            SubMsg::new(factory_burn),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Burn { amount: vec![coin(100, "uosmo")] })),
+        ]);
+
+        //Update Config: Error - invalid sink address
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig { paired_asset: None, burn_strategy: Some(BurnStrategy::Sink { addr: String::from("SINK0000") }) },
+        ).unwrap_err();
+
+        //Sink: all assets are sent to the sink
+        let _res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender88", &[]),
+            ExecuteMsg::UpdateConfig { paired_asset: None, burn_strategy: Some(BurnStrategy::Sink { addr: String::from("sink0000") }) },
+        ).unwrap();
+        let res = reply(deps.as_mut(), mock_env(), balancer_reply()).unwrap();
+        assert_eq!(res.messages, vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("sink0000"),
+            amount: vec![coin(50, factory_denom.clone()), coin(100, "uosmo")],
+        }))]);
+
+        //Burned totals add up across burns
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Burned {}).unwrap();
+        let resp: BurnedResponse = from_json(&res).unwrap();
+        assert_eq!(resp.burned, vec![coin(150, factory_denom), coin(300, "uosmo")]);
+    }
+
+    #[test]
+    fn migrate_legacy_config(){
+        let mut deps = mock_dependencies();
+
+        //v0.1.0 config without a burn strategy
+        cw2::set_contract_version(deps.as_mut().storage, "simple_LP", "0.1.0").unwrap();
+        legacy::CONFIG.save(deps.as_mut().storage, &legacy::Config {
+            owner: Addr::unchecked("sender88"),
+            paired_asset: String::from("uatom"),
+        }).unwrap();
+
+        let _res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+        assert_eq!(from_json::<Config>(&res).unwrap(), Config {
+            owner: Addr::unchecked("sender88"),
+            paired_asset: String::from("uatom"),
+            burn_strategy: BurnStrategy::Burn,
+        });
+        assert_eq!(cw2::get_contract_version(deps.as_ref().storage).unwrap().version, env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1 @@
+mod contract_tests;